@@ -4,6 +4,9 @@
 //! sharing a common [`EquipItem`] action but recording which slot they correspond to
 //! via the [`EquipHotbarIndex`] component.
 //! Each of these actions is bound to a different key from the number row on the keyboard.
+//!
+//! Scrolling the mouse wheel cycles through the slots, one slot per notch,
+//! using the [`CycleItem`] action.
 
 use bevy::prelude::*;
 use bevy_enhanced_input::prelude::*;
@@ -13,6 +16,7 @@ fn main() {
         .add_plugins((DefaultPlugins, EnhancedInputPlugin))
         .add_input_context::<Player>()
         .add_observer(equip)
+        .add_observer(cycle)
         .add_systems(Startup, spawn)
         .run();
 }
@@ -44,6 +48,8 @@ fn spawn(mut commands: Commands) {
             (Action::<EquipItem>::new(), EquipHotbarIndex(6), bindings![KeyCode::Digit7]),
             (Action::<EquipItem>::new(), EquipHotbarIndex(7), bindings![KeyCode::Digit8]),
             (Action::<EquipItem>::new(), EquipHotbarIndex(8), bindings![KeyCode::Digit9]),
+            (Action::<CycleItem>::new(), CycleOffset(1), bindings![WheelDirection::Down]),
+            (Action::<CycleItem>::new(), CycleOffset(-1), bindings![WheelDirection::Up]),
             ]
         ),
     ));
@@ -58,12 +64,20 @@ fn equip(
     let mut hotbar = hotbars.get_mut(equip.context).unwrap();
 
     hotbar.equipped = equip_index.0;
+    hotbar.print_equipped();
+}
 
-    if let Some(item) = &hotbar.inventory[hotbar.equipped] {
-        println!("equipped item: {item:?}");
-    } else {
-        println!("equipped nothing");
-    }
+fn cycle(
+    cycle: On<Start<CycleItem>>,
+    actions: Query<&CycleOffset>,
+    mut hotbars: Query<&mut Hotbar, With<Player>>,
+) {
+    let offset = actions.get(cycle.event().action).unwrap();
+    let mut hotbar = hotbars.get_mut(cycle.context).unwrap();
+
+    let len = hotbar.inventory.len() as isize;
+    hotbar.equipped = (hotbar.equipped as isize + offset.0).rem_euclid(len) as usize;
+    hotbar.print_equipped();
 }
 
 #[derive(Component)]
@@ -77,12 +91,30 @@ struct EquipItem;
 #[derive(Component)]
 struct EquipHotbarIndex(usize);
 
+#[derive(InputAction)]
+#[action_output(bool)]
+struct CycleItem;
+
+/// The number of slots to move by when the [`CycleItem`] action is triggered for this entity.
+#[derive(Component)]
+struct CycleOffset(isize);
+
 #[derive(Component)]
 struct Hotbar {
     inventory: Vec<Option<Item>>,
     equipped: usize,
 }
 
+impl Hotbar {
+    fn print_equipped(&self) {
+        if let Some(item) = &self.inventory[self.equipped] {
+            println!("equipped item: {item:?}");
+        } else {
+            println!("equipped nothing");
+        }
+    }
+}
+
 #[derive(Debug)]
 enum Item {
     Torch,
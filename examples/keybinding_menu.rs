@@ -4,7 +4,7 @@
 //! implements [`Serialize`] and [`Deserialize`] via [`serde`], allowing easy saving and loading
 //! of user preferences in the file format of your choice (commonly .ron or .json).
 
-use core::{error::Error, fmt::Write};
+use core::{error::Error, fmt::Write, mem};
 use std::fs;
 
 use bevy::{
@@ -343,8 +343,7 @@ fn replace_binding(
     let (_, mut conflict_button) = buttons
         .get_mut(dialog.conflict_button)
         .expect("binding conflict should point to a button");
-    let binding = conflict_button.binding;
-    conflict_button.binding = Binding::None;
+    let binding = mem::replace(&mut conflict_button.binding, Binding::None);
 
     let (name, mut binding_button) = buttons
         .get_mut(dialog.binding_button)
@@ -376,7 +375,7 @@ fn apply(
         let field_value = settings
             .path_mut::<[Binding; BINDINGS_COUNT]>(info.field_name)
             .expect("fields with bindings should be stored as Vec");
-        field_value[info.index] = button.binding;
+        field_value[info.index] = button.binding.clone();
     }
 
     commands.trigger(SettingsChanged);
@@ -500,7 +499,7 @@ struct ConflictDialog {
 /// actions like "forward" to [`GamepadAxis::LeftStickX`].
 ///
 /// If you want to assign a specific part of the axis, such as the positive part of [`GamepadAxis::LeftStickX`],
-/// you need to create your own binding enum. However, this approach is mostly used in emulators rather than games.
+/// you can use [`Binding::GamepadAxisDirection`]. However, this approach is mostly used in emulators rather than games.
 ///
 /// So in this example we assign only keyboard and mouse bindings.
 #[derive(Resource, Reflect, Clone, Deserialize, Serialize)]
@@ -588,22 +587,22 @@ fn player_bundle(settings: InputSettings) -> impl Bundle {
                     Scale::splat(10.0),
                     Bindings::spawn((
                         Cardinal {
-                            north: settings.forward[0],
-                            east: settings.right[0],
-                            south: settings.backward[0],
-                            west: settings.left[0],
+                            north: settings.forward[0].clone(),
+                            east: settings.right[0].clone(),
+                            south: settings.backward[0].clone(),
+                            west: settings.left[0].clone(),
                         },
                         Cardinal {
-                            north: settings.forward[1],
-                            east: settings.right[1],
-                            south: settings.backward[1],
-                            west: settings.left[1],
+                            north: settings.forward[1].clone(),
+                            east: settings.right[1].clone(),
+                            south: settings.backward[1].clone(),
+                            west: settings.left[1].clone(),
                         },
                         Cardinal {
-                            north: settings.forward[2],
-                            east: settings.right[2],
-                            south: settings.backward[2],
-                            west: settings.left[2],
+                            north: settings.forward[2].clone(),
+                            east: settings.right[2].clone(),
+                            south: settings.backward[2].clone(),
+                            west: settings.left[2].clone(),
                         },
                     )),
                 ),
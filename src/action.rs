@@ -28,6 +28,9 @@
 //! See the section on [pull-style action handling](crate#pull-style-polling-action-state)
 //! in the library documentation for more details.
 //!
+//! Actions that are defined at runtime, for example from scripts, can use [`DynamicAction`]
+//! instead of a Rust type.
+//!
 //! # Configuring actions
 //!
 //! The behavior of actions can be customized using the [`ActionSettings`] component,
@@ -45,6 +48,7 @@
 //! This is useful for simulating input during cutscenes,
 //! testing, networked replication, AI-controlled players, game replays, or other scenarios where you want to control the action state directly.
 
+pub mod dynamic;
 pub mod events;
 pub mod fns;
 pub mod mock;
@@ -188,7 +192,7 @@ pub struct ActionSettings {
     /// Specifies whether this action should swallow any [`Bindings`]
     /// bound to it or allow them to pass through to affect actions that evaluated later.
     ///
-    /// Actions are ordered by the maximum number of [`ModKeys`] in their bindings.
+    /// Actions are ordered by the maximum number of [`ModKeys`] and [`ModButtons`] in their bindings.
     /// For example, an action with a `Ctrl + C` binding is evaluated before one with just
     /// a `C` binding. If actions have the same modifier count, they are ordered by their
     /// spawn order.
@@ -201,6 +205,15 @@ pub struct ActionSettings {
     ///
     /// By default set to `false`.
     pub consume_input: bool,
+
+    /// Reject bindings when any modifier keys other than their [`ModKeys`] are pressed.
+    ///
+    /// For example, a binding to `S` won't trigger while `Ctrl` is held, and a binding to
+    /// `Ctrl + S` won't trigger while `Ctrl + Shift` is held. Unlike relying on
+    /// [`Self::consume_input`], this works regardless of the evaluation order and across contexts.
+    ///
+    /// By default set to `false`.
+    pub exact_mod_keys: bool,
 }
 
 /// Defines how [`ActionValue`] is calculated when multiple inputs are evaluated with the
@@ -0,0 +1,180 @@
+/*!
+Actions defined at runtime without Rust types.
+
+[`DynamicAction`] can be used instead of [`Action<A>`] when actions come from data, such as
+configuration files or scripts. It works with [`Actions<C>`], bindings, conditions and modifiers
+just like typed actions, but stores its value only in [`ActionValue`] and triggers
+[`DynamicActionEvent`] instead of typed [action events](super::events).
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{Press, *};
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .add_input_context::<Player>()
+    .add_observer(|event: On<DynamicActionEvent>| {
+        if event.kind == ActionEvents::FIRE && event.name.as_str() == "jump" {
+            // ...
+        }
+    })
+    .finish();
+
+app.world_mut().spawn((
+    Player,
+    actions!(Player[(
+        DynamicAction::new("jump", ActionValueDim::Bool),
+        Press::default(),
+        bindings![KeyCode::Space],
+    )]),
+));
+
+#[derive(Component)]
+struct Player;
+```
+*/
+
+use alloc::borrow::Cow;
+
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use log::debug;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::fns::ActionFns;
+use crate::prelude::*;
+
+/// Component that represents an action defined at runtime.
+///
+/// Like [`Action<A>`], entities with this component needs to be spawned with [`ActionOf<C>`]
+/// relationship in order to be evaluated.
+///
+/// On insertion, [`Name`] is set to [`Self::name`] and [`ActionValue`] is reset
+/// to zero of [`Self::dim`].
+#[derive(Component, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+#[component(on_insert = on_insert, immutable)]
+#[require(
+    Name,
+    ActionFns::dynamic(),
+    ActionValue::zero(ActionValueDim::Bool),
+    ActionSettings,
+    TriggerState,
+    ActionEvents,
+    ActionTime,
+    ActionMock
+)]
+pub struct DynamicAction {
+    /// Name that will be passed to [`DynamicActionEvent`].
+    pub name: Name,
+
+    /// Dimension of the action value.
+    pub dim: ActionValueDim,
+}
+
+impl DynamicAction {
+    /// Creates a new instance with the given name and value dimension.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>, dim: ActionValueDim) -> Self {
+        Self {
+            name: Name::new(name),
+            dim,
+        }
+    }
+}
+
+fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
+    let action = world.get::<DynamicAction>(ctx.entity).unwrap().clone();
+    let mut entity = world.entity_mut(ctx.entity);
+
+    *entity.get_mut::<Name>().unwrap() = action.name;
+    *entity.get_mut::<ActionValue>().unwrap() = ActionValue::zero(action.dim);
+}
+
+/// Untyped event for [`DynamicAction`].
+///
+/// Triggered on the context entity for each event from [`ActionEvents`], at the same point
+/// and in the same order as typed [action events](super::events).
+/// Since there are no separate types, the kind of the event is stored in [`Self::kind`].
+#[derive(EntityEvent, Debug, Clone)]
+pub struct DynamicActionEvent {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Action that triggered this event.
+    pub action: Entity,
+
+    /// Name of the action from [`DynamicAction::name`].
+    pub name: Name,
+
+    /// Kind of the event.
+    ///
+    /// Always contains exactly one flag.
+    pub kind: ActionEvents,
+
+    /// Current action value.
+    pub value: ActionValue,
+
+    /// Current action state.
+    pub state: TriggerState,
+
+    /// Time that this action has been in [`TriggerState::Fired`] state.
+    pub fired_secs: f32,
+
+    /// Total time this action has been in both [`TriggerState::Ongoing`] and [`TriggerState::Fired`].
+    pub elapsed_secs: f32,
+}
+
+pub(super) fn store_value(action: &mut EntityMut, value: ActionValue) {
+    let dim = action
+        .get::<DynamicAction>()
+        .expect("entity should be a dynamic action")
+        .dim;
+
+    // Convert like typed actions do, so the value always matches the declared dimension.
+    if value.dim() != dim {
+        *action.get_mut::<ActionValue>().unwrap() = value.convert(dim);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn trigger(
+    commands: &mut Commands,
+    context: Entity,
+    action: Entity,
+    name: &Name,
+    state: TriggerState,
+    events: ActionEvents,
+    value: ActionValue,
+    time: ActionTime,
+) {
+    for (event_name, kind) in events.iter_names() {
+        debug!("triggering `{event_name}` for `{name}` (`{action}`) for context `{context}`");
+
+        commands.trigger(DynamicActionEvent {
+            context,
+            action,
+            name: name.clone(),
+            kind,
+            value,
+            state,
+            fired_secs: time.fired_secs,
+            elapsed_secs: time.elapsed_secs,
+        });
+    }
+}
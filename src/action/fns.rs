@@ -1,16 +1,26 @@
 use bevy::prelude::*;
 use log::{debug, warn};
 
+use super::dynamic;
 use crate::prelude::{Cancel, *};
 
-/// Functions for type `A` associated with [`Action<A>`] component.
+/// Functions for type `A` associated with [`Action<A>`] component or for [`DynamicAction`].
 ///
 /// Used to trigger events for it and update its value.
 #[derive(Component, Clone, Copy)]
 #[component(immutable)]
 pub(crate) struct ActionFns {
     store_value: fn(&mut EntityMut, ActionValue),
-    trigger: fn(&mut Commands, Entity, Entity, TriggerState, ActionEvents, ActionValue, ActionTime),
+    trigger: fn(
+        &mut Commands,
+        Entity,
+        Entity,
+        &Name,
+        TriggerState,
+        ActionEvents,
+        ActionValue,
+        ActionTime,
+    ),
 }
 
 impl ActionFns {
@@ -22,6 +32,14 @@ impl ActionFns {
         }
     }
 
+    /// Creates a new instance with function pointers for [`DynamicAction`].
+    pub(super) fn dynamic() -> Self {
+        Self {
+            store_value: dynamic::store_value,
+            trigger: dynamic::trigger,
+        }
+    }
+
     /// Stores the given value in the entity's [`Action<A>`] component for which this instance was created.
     pub(crate) fn store_value(&self, action: &mut EntityMut, value: ActionValue) {
         (self.store_value)(action, value);
@@ -34,12 +52,13 @@ impl ActionFns {
         commands: &mut Commands,
         context: Entity,
         action: Entity,
+        name: &Name,
         state: TriggerState,
         events: ActionEvents,
         value: ActionValue,
         time: ActionTime,
     ) {
-        (self.trigger)(commands, context, action, state, events, value, time);
+        (self.trigger)(commands, context, action, name, state, events, value, time);
     }
 }
 
@@ -61,10 +80,12 @@ fn store_value<A: InputAction>(action: &mut EntityMut, value: ActionValue) {
     **action = value.into();
 }
 
+#[allow(clippy::too_many_arguments)]
 fn trigger<A: InputAction>(
     commands: &mut Commands,
     context: Entity,
     action: Entity,
+    _name: &Name,
     state: TriggerState,
     events: ActionEvents,
     value: ActionValue,
@@ -224,6 +245,7 @@ mod tests {
             &mut world.commands(),
             Entity::PLACEHOLDER,
             Entity::PLACEHOLDER,
+            &Name::default(),
             target_state,
             events,
             false.into(),
@@ -11,20 +11,26 @@
 //!
 //! For an exhaustive list of available input devices, see the [`Binding`] enum.
 
+pub mod gamepad_stick;
+pub mod input_source;
+pub mod mod_buttons;
 pub mod mod_keys;
 pub mod relationship;
+pub mod wheel_notch;
 
+use alloc::vec;
 use core::fmt::{self, Display, Formatter};
 
 use bevy::{
-    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    ecs::{entity::EntityHashSet, lifecycle::HookContext, world::DeferredWorld},
+    input::keyboard::Key,
     prelude::*,
 };
 use log::{Level, error, log_enabled, warn};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::*;
+use crate::{binding::input_source::CustomBindings, condition::DEFAULT_ACTUATION, prelude::*};
 
 /// A an input bound to an [`Action<C>`].
 ///
@@ -35,7 +41,11 @@ use crate::prelude::*;
 ///
 /// If the action's dimension differs from the captured input, it will be converted using
 /// [`ActionValue::convert`](crate::action::value::ActionValue::convert).
-#[derive(Component, Debug, PartialEq, Clone, Copy)]
+///
+/// Buttons that were pressed and released between two evaluations of a context
+/// are captured as pressed for one evaluation, so short taps aren't lost on low
+/// frame rates or in fixed schedules.
+#[derive(Component, Debug, PartialEq, Clone)]
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
@@ -46,11 +56,24 @@ use crate::prelude::*;
     all(feature = "reflect", feature = "serialize"),
     reflect(Serialize, Deserialize)
 )]
-#[component(on_insert = on_insert, immutable)]
+#[component(on_insert = on_insert, on_replace = on_replace, immutable)]
 #[require(FirstActivation)]
 pub enum Binding {
     /// Keyboard button, captured as [`ActionValue::Bool`].
     Keyboard { key: KeyCode, mod_keys: ModKeys },
+    /// Keyboard button by its layout-dependent meaning, captured as [`ActionValue::Bool`].
+    ///
+    /// Unlike [`Self::Keyboard`], which refers to the physical location of a key,
+    /// this follows the user's keyboard layout. For example, [`Key::Character`] with `"z"`
+    /// will be triggered by the key labeled "Z" on both QWERTY and QWERTZ keyboards.
+    ///
+    /// Read from [`ButtonInput<Key>`], which is updated from
+    /// [`KeyboardInput`](bevy::input::keyboard::KeyboardInput) messages.
+    /// Modifiers are still matched by their physical keys.
+    ///
+    /// Consuming a logical key also consumes the physical keys that produced it,
+    /// and consuming [`Self::Keyboard`] also consumes the logical key it produced.
+    LogicalKey { key: Key, mod_keys: ModKeys },
     /// Mouse button, captured as [`ActionValue::Bool`].
     MouseButton {
         button: MouseButton,
@@ -92,10 +115,95 @@ pub enum Binding {
     /// struct PlayerCam;
     /// ```
     MouseWheel { mod_keys: ModKeys },
+    /// Mouse wheel notch in the given direction, captured as [`ActionValue::Bool`].
+    ///
+    /// Captures one press per notch. See the [module documentation](wheel_notch)
+    /// for details.
+    ///
+    /// Consumed independently from other directions, but consuming [`Self::MouseWheel`]
+    /// consumes all notches, and consuming a notch also consumes [`Self::MouseWheel`].
+    MouseWheelNotch {
+        direction: WheelDirection,
+        mod_keys: ModKeys,
+    },
+    /// Absolute cursor position inside the window, captured as [`ActionValue::Axis2D`].
+    ///
+    /// Read from the [`PrimaryWindow`](bevy::window::PrimaryWindow), or from the window
+    /// assigned to the context using [`ContextWindow`].
+    ///
+    /// The origin is the top-left corner of the window. If `normalized` is set,
+    /// the position is divided by the window size, resulting in values from 0 to 1.
+    /// Otherwise, the position is in logical pixels.
+    ///
+    /// Captured as zero when the cursor is outside the window or when the `window` feature is disabled.
+    CursorPosition { normalized: bool },
     /// Gamepad button, captured as [`ActionValue::Axis1D`].
-    GamepadButton(GamepadButton),
+    ///
+    /// Activates only while all `mod_buttons` are held on the same gamepad.
+    GamepadButton {
+        button: GamepadButton,
+        mod_buttons: ModButtons,
+    },
     /// Gamepad stick axis, captured as [`ActionValue::Axis1D`].
-    GamepadAxis(GamepadAxis),
+    ///
+    /// Activates only while all `mod_buttons` are held on the same gamepad.
+    GamepadAxis {
+        axis: GamepadAxis,
+        mod_buttons: ModButtons,
+    },
+    /// Both axes of a gamepad stick, captured as [`ActionValue::Axis2D`].
+    ///
+    /// Unlike [`Axial`] with two [`Self::GamepadAxis`] bindings, binding-level modifiers
+    /// like [`DeadZone`] see the whole stick, making radial dead zones possible per stick.
+    ///
+    /// Consuming the stick consumes both of its axes.
+    GamepadStick(GamepadStick),
+    /// One direction of a gamepad axis, captured as [`ActionValue::Bool`].
+    ///
+    /// Activates when the axis is pushed in the given direction by at least `threshold`,
+    /// allowing a half of a stick to be bound as a digital button.
+    ///
+    /// Consumed independently from the opposite direction. Consuming the whole axis
+    /// via [`Self::GamepadAxis`] or [`Self::GamepadStick`] consumes both directions,
+    /// and consuming a direction also consumes the whole axis.
+    ///
+    /// See also [`Self::gamepad_axis_direction`].
+    GamepadAxisDirection {
+        axis: GamepadAxis,
+        positive: bool,
+        threshold: f32,
+    },
+    /// Any touch on the screen, captured as [`ActionValue::Bool`].
+    AnyTouch,
+    /// Touch inside a screen-space rectangle in logical pixels, captured as [`ActionValue::Bool`].
+    ///
+    /// The origin is the top-left corner of the window, matching
+    /// [`Touch::position`](bevy::input::touch::Touch::position).
+    TouchArea(Rect),
+    /// Touch movement, captured as [`ActionValue::Axis2D`].
+    ///
+    /// Averaged over all active touches. Similar to [`Self::MouseMotion`],
+    /// the Y axis points down.
+    TouchDrag,
+    /// Input from a registered [`InputSource`], captured as the value returned by the source.
+    ///
+    /// Captured as `false` if the source is not registered or its resource is missing.
+    ///
+    /// See also [`Self::custom`].
+    Custom(CustomInput),
+    /// Value of another action, captured as its current [`ActionValue`].
+    ///
+    /// Allows deriving actions from other actions, e.g. a "sprint" action that
+    /// activates when "move" is actuated with the sprint modifier held.
+    ///
+    /// Actions within the same context are evaluated after the actions they reference,
+    /// so the value is always from the current frame. If the referenced action belongs
+    /// to another context, the value is current only if that context is evaluated first
+    /// (see [`ContextPriority`]). Otherwise, or if the bindings form a cycle (reported as an error),
+    /// the value from the previous evaluation is used.
+    ///
+    /// Never consumed.
+    Action(Entity),
     /// Any key, mouse button, or gamepad button, captured as [`ActionValue::Bool`].
     ///
     /// If used with a context with [`GamepadDevice::Single`], it will only
@@ -128,22 +236,66 @@ impl Binding {
         }
     }
 
+    /// Returns [`Self::CursorPosition`] in logical pixels.
+    #[must_use]
+    pub const fn cursor_position() -> Self {
+        Self::CursorPosition { normalized: false }
+    }
+
+    /// Returns [`Self::CursorPosition`] normalized to the window size.
+    #[must_use]
+    pub const fn normalized_cursor_position() -> Self {
+        Self::CursorPosition { normalized: true }
+    }
+
+    /// Returns [`Self::GamepadAxisDirection`] with [`DEFAULT_ACTUATION`] as the threshold.
+    #[must_use]
+    pub const fn gamepad_axis_direction(axis: GamepadAxis, positive: bool) -> Self {
+        Self::GamepadAxisDirection {
+            axis,
+            positive,
+            threshold: DEFAULT_ACTUATION,
+        }
+    }
+
+    /// Returns [`Self::Custom`] for the given input of source `S`.
+    #[must_use]
+    pub const fn custom<S: InputSource>(input: u32) -> Self {
+        Self::Custom(CustomInput::new::<S>(input))
+    }
+
     /// Returns the amount of associated keyboard modifiers.
     #[must_use]
-    pub fn mod_keys_count(self) -> usize {
+    pub fn mod_keys_count(&self) -> usize {
         self.mod_keys().iter_names().count()
     }
 
+    /// Returns the amount of associated keyboard modifiers and gamepad modifier buttons.
+    #[must_use]
+    pub fn mods_count(&self) -> usize {
+        self.mod_keys_count() + self.mod_buttons().iter_names().count()
+    }
+
     /// Returns associated keyboard modifiers.
     #[must_use]
-    pub const fn mod_keys(self) -> ModKeys {
-        match self {
+    pub const fn mod_keys(&self) -> ModKeys {
+        match *self {
             Binding::Keyboard { mod_keys, .. }
+            | Binding::LogicalKey { mod_keys, .. }
             | Binding::MouseButton { mod_keys, .. }
             | Binding::MouseMotion { mod_keys }
-            | Binding::MouseWheel { mod_keys } => mod_keys,
-            Binding::GamepadButton(_)
-            | Binding::GamepadAxis(_)
+            | Binding::MouseWheel { mod_keys }
+            | Binding::MouseWheelNotch { mod_keys, .. } => mod_keys,
+            Binding::CursorPosition { .. }
+            | Binding::GamepadButton { .. }
+            | Binding::GamepadAxis { .. }
+            | Binding::GamepadStick(_)
+            | Binding::GamepadAxisDirection { .. }
+            | Binding::AnyTouch
+            | Binding::TouchArea(_)
+            | Binding::TouchDrag
+            | Binding::Custom(_)
+            | Binding::Action(_)
             | Binding::AnyKey
             | Binding::None => ModKeys::empty(),
         }
@@ -151,13 +303,43 @@ impl Binding {
 
     /// Returns new instance without any keyboard modifiers.
     ///
-    /// # Panics
-    ///
-    /// Panics when called on [`Self::GamepadButton`] or [`Self::GamepadAxis`].
+    /// Prints error and returns the binding unchanged for bindings that don't support keyboard
+    /// modifiers. See [`InputModKeys::with_mod_keys`] for details.
     #[must_use]
     pub fn without_mod_keys(self) -> Self {
         self.with_mod_keys(ModKeys::empty())
     }
+
+    /// Returns associated gamepad modifier buttons.
+    #[must_use]
+    pub const fn mod_buttons(&self) -> ModButtons {
+        match *self {
+            Binding::GamepadButton { mod_buttons, .. }
+            | Binding::GamepadAxis { mod_buttons, .. } => mod_buttons,
+            Binding::CursorPosition { .. }
+            | Binding::Keyboard { .. }
+            | Binding::LogicalKey { .. }
+            | Binding::MouseButton { .. }
+            | Binding::MouseMotion { .. }
+            | Binding::MouseWheel { .. }
+            | Binding::MouseWheelNotch { .. }
+            | Binding::GamepadStick(_)
+            | Binding::GamepadAxisDirection { .. }
+            | Binding::AnyTouch
+            | Binding::TouchArea(_)
+            | Binding::TouchDrag
+            | Binding::Custom(_)
+            | Binding::Action(_)
+            | Binding::AnyKey
+            | Binding::None => ModButtons::empty(),
+        }
+    }
+
+    /// Returns new instance without any gamepad modifier buttons.
+    #[must_use]
+    pub fn without_mod_buttons(self) -> Self {
+        self.with_mod_buttons(ModButtons::empty())
+    }
 }
 
 impl Display for Binding {
@@ -166,14 +348,35 @@ impl Display for Binding {
         if !mod_keys.is_empty() {
             write!(f, "{mod_keys} + ")?;
         }
+        let mod_buttons = self.mod_buttons();
+        if !mod_buttons.is_empty() {
+            write!(f, "{mod_buttons} + ")?;
+        }
 
         match self {
             Binding::Keyboard { key, .. } => write!(f, "{key:?}"),
+            Binding::LogicalKey {
+                key: Key::Character(c),
+                ..
+            } => write!(f, "{}", c.to_uppercase()),
+            Binding::LogicalKey { key, .. } => write!(f, "{key:?}"),
             Binding::MouseButton { button, .. } => write!(f, "Mouse {button:?}"),
             Binding::MouseMotion { .. } => write!(f, "Mouse Motion"),
             Binding::MouseWheel { .. } => write!(f, "Scroll Wheel"),
-            Binding::GamepadButton(gamepad_button) => write!(f, "{gamepad_button:?}"),
-            Binding::GamepadAxis(gamepad_axis) => write!(f, "{gamepad_axis:?}"),
+            Binding::MouseWheelNotch { direction, .. } => write!(f, "Scroll {direction:?}"),
+            Binding::CursorPosition { .. } => write!(f, "Cursor Position"),
+            Binding::GamepadButton { button, .. } => write!(f, "{button:?}"),
+            Binding::GamepadAxis { axis, .. } => write!(f, "{axis:?}"),
+            Binding::GamepadStick(stick) => write!(f, "{stick:?} Stick"),
+            Binding::GamepadAxisDirection { axis, positive, .. } => {
+                let sign = if *positive { '+' } else { '-' };
+                write!(f, "{axis:?}{sign}")
+            }
+            Binding::AnyTouch => write!(f, "Any Touch"),
+            Binding::TouchArea(_) => write!(f, "Touch Area"),
+            Binding::TouchDrag => write!(f, "Touch Drag"),
+            Binding::Custom(input) => write!(f, "{} {}", input.source, input.input),
+            Binding::Action(action) => write!(f, "Action {action}"),
             Binding::AnyKey => write!(f, "Any Key"),
             Binding::None => write!(f, "None"),
         }
@@ -189,6 +392,15 @@ impl From<KeyCode> for Binding {
     }
 }
 
+impl From<Key> for Binding {
+    fn from(key: Key) -> Self {
+        Self::LogicalKey {
+            key,
+            mod_keys: Default::default(),
+        }
+    }
+}
+
 impl From<MouseButton> for Binding {
     fn from(button: MouseButton) -> Self {
         Self::MouseButton {
@@ -199,14 +411,35 @@ impl From<MouseButton> for Binding {
 }
 
 impl From<GamepadButton> for Binding {
-    fn from(value: GamepadButton) -> Self {
-        Self::GamepadButton(value)
+    fn from(button: GamepadButton) -> Self {
+        Self::GamepadButton {
+            button,
+            mod_buttons: Default::default(),
+        }
     }
 }
 
 impl From<GamepadAxis> for Binding {
-    fn from(value: GamepadAxis) -> Self {
-        Self::GamepadAxis(value)
+    fn from(axis: GamepadAxis) -> Self {
+        Self::GamepadAxis {
+            axis,
+            mod_buttons: Default::default(),
+        }
+    }
+}
+
+impl From<WheelDirection> for Binding {
+    fn from(direction: WheelDirection) -> Self {
+        Self::MouseWheelNotch {
+            direction,
+            mod_keys: Default::default(),
+        }
+    }
+}
+
+impl From<GamepadStick> for Binding {
+    fn from(value: GamepadStick) -> Self {
+        Self::GamepadStick(value)
     }
 }
 
@@ -221,16 +454,31 @@ impl<I: Into<Binding>> InputModKeys for I {
     /// Returns new instance with the replaced keyboard modifiers.
     ///
     /// Prints error and does nothing when called on [`Binding::GamepadButton`],
-    /// [`Binding::GamepadAxis`], [`Binding::AnyKey`] or [`Binding::None`].
+    /// [`Binding::GamepadAxis`], [`Binding::GamepadStick`], [`Binding::GamepadAxisDirection`],
+    /// [`Binding::CursorPosition`], touch bindings, [`Binding::Custom`], [`Binding::Action`],
+    /// [`Binding::AnyKey`] or [`Binding::None`].
     fn with_mod_keys(self, mod_keys: ModKeys) -> Binding {
         let binding = self.into();
         match binding {
             Binding::Keyboard { key, .. } => Binding::Keyboard { key, mod_keys },
+            Binding::LogicalKey { key, .. } => Binding::LogicalKey { key, mod_keys },
             Binding::MouseButton { button, .. } => Binding::MouseButton { button, mod_keys },
             Binding::MouseMotion { .. } => Binding::MouseMotion { mod_keys },
             Binding::MouseWheel { .. } => Binding::MouseWheel { mod_keys },
-            Binding::GamepadButton { .. }
+            Binding::MouseWheelNotch { direction, .. } => Binding::MouseWheelNotch {
+                direction,
+                mod_keys,
+            },
+            Binding::CursorPosition { .. }
+            | Binding::GamepadButton { .. }
             | Binding::GamepadAxis { .. }
+            | Binding::GamepadStick { .. }
+            | Binding::GamepadAxisDirection { .. }
+            | Binding::AnyTouch
+            | Binding::TouchArea(_)
+            | Binding::TouchDrag
+            | Binding::Custom(_)
+            | Binding::Action(_)
             | Binding::None
             | Binding::AnyKey => {
                 error!("can't add `{mod_keys:?}` to `{binding:?}`");
@@ -240,13 +488,65 @@ impl<I: Into<Binding>> InputModKeys for I {
     }
 }
 
+/// A trait to ergonomically assign gamepad modifier buttons to any type that can be converted into a [`Binding`].
+pub trait InputModButtons {
+    /// Returns a binding with assigned gamepad modifier buttons.
+    #[must_use]
+    fn with_mod_buttons(self, mod_buttons: ModButtons) -> Binding;
+}
+
+impl<I: Into<Binding>> InputModButtons for I {
+    /// Returns new instance with the replaced gamepad modifier buttons.
+    ///
+    /// Prints error and does nothing when called on non-gamepad bindings or [`Binding::GamepadStick`].
+    fn with_mod_buttons(self, mod_buttons: ModButtons) -> Binding {
+        let binding = self.into();
+        match binding {
+            Binding::GamepadButton { button, .. } => Binding::GamepadButton {
+                button,
+                mod_buttons,
+            },
+            Binding::GamepadAxis { axis, .. } => Binding::GamepadAxis { axis, mod_buttons },
+            _ => {
+                if !mod_buttons.is_empty() {
+                    error!("can't add `{mod_buttons:?}` to `{binding:?}`");
+                }
+                binding
+            }
+        }
+    }
+}
+
 fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
+    if let Some(mut changes) = world.get_resource_mut::<BindingChanges>() {
+        changes.set_changed();
+    }
+
     let mut entity = world.entity_mut(ctx.entity);
 
     let mut first_activation = entity.get_mut::<FirstActivation>().unwrap();
     **first_activation = true;
 
+    if let Binding::Custom(input) = world.get::<Binding>(ctx.entity).unwrap() {
+        let input = input.clone();
+        if let Some(mut bindings) = world.get_resource_mut::<CustomBindings>() {
+            bindings.add(&input);
+        }
+    }
+
+    if let Binding::Action(source) = *world.get::<Binding>(ctx.entity).unwrap()
+        && let Some(action) = world.get::<BindingOf>(ctx.entity).map(|b| **b)
+        && depends_on(&world, source, action)
+    {
+        error!(
+            "`{}` binds `{action}` to `{source}`, which creates a cycle; \
+            the value from the previous evaluation will be used for one of them",
+            ctx.entity
+        );
+    }
+
     if log_enabled!(Level::Warn) {
+        let entity = world.entity(ctx.entity);
         if let Some(action) = entity.get::<BindingOf>().map(|b| **b) {
             if world.get::<TriggerState>(action).is_none() {
                 let binding = world.get::<Binding>(ctx.entity).unwrap();
@@ -265,6 +565,50 @@ fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
     }
 }
 
+fn on_replace(mut world: DeferredWorld, ctx: HookContext) {
+    if let Some(mut changes) = world.get_resource_mut::<BindingChanges>() {
+        changes.set_changed();
+    }
+
+    if let Binding::Custom(input) = world.get::<Binding>(ctx.entity).unwrap() {
+        let input = input.clone();
+        if let Some(mut bindings) = world.get_resource_mut::<CustomBindings>() {
+            bindings.remove(&input);
+        }
+    }
+}
+
+/// Returns `true` if `action` can be reached from `source` via [`Binding::Action`].
+fn depends_on(world: &DeferredWorld, source: Entity, action: Entity) -> bool {
+    let mut visited = EntityHashSet::default();
+    let mut stack = vec![source];
+    while let Some(current) = stack.pop() {
+        if current == action {
+            return true;
+        }
+        if !visited.insert(current) {
+            continue;
+        }
+
+        let Some(bindings) = world.get::<Bindings>(current) else {
+            continue;
+        };
+        for binding in bindings {
+            if let Some(&Binding::Action(source)) = world.get::<Binding>(binding) {
+                stack.push(source);
+            }
+        }
+    }
+
+    false
+}
+
+/// Marked as changed whenever a [`Binding`] is inserted, replaced or removed.
+///
+/// Used to invalidate data that is derived from bindings and cached across evaluations.
+#[derive(Resource, Default)]
+pub(crate) struct BindingChanges;
+
 /// Tracks whether the input defined by [`Binding`] was active at least once.
 ///
 /// Used to prevent newly created contexts from reacting to currently active inputs
@@ -298,6 +642,13 @@ mod tests {
             .to_string(),
             "Ctrl + KeyA"
         );
+        assert_eq!(
+            Binding::from(Key::Character("z".into()))
+                .with_mod_keys(ModKeys::CONTROL)
+                .to_string(),
+            "Ctrl + Z"
+        );
+        assert_eq!(Binding::from(Key::Enter).to_string(), "Enter");
         assert_eq!(
             Binding::MouseButton {
                 button: MouseButton::Left,
@@ -320,13 +671,54 @@ mod tests {
             .to_string(),
             "Scroll Wheel"
         );
+        assert_eq!(Binding::from(WheelDirection::Up).to_string(), "Scroll Up");
+        assert_eq!(
+            WheelDirection::Down
+                .with_mod_keys(ModKeys::CONTROL)
+                .to_string(),
+            "Ctrl + Scroll Down"
+        );
+        assert_eq!(Binding::cursor_position().to_string(), "Cursor Position");
         assert_eq!(
-            Binding::GamepadAxis(GamepadAxis::LeftStickX).to_string(),
+            Binding::from(GamepadAxis::LeftStickX).to_string(),
             "LeftStickX"
         );
+        assert_eq!(Binding::from(GamepadButton::North).to_string(), "North");
+        assert_eq!(
+            GamepadButton::South
+                .with_mod_buttons(ModButtons::LEFT_TRIGGER)
+                .to_string(),
+            "LeftTrigger + South"
+        );
+        assert_eq!(
+            Binding::GamepadStick(GamepadStick::Left).to_string(),
+            "Left Stick"
+        );
+        assert_eq!(
+            Binding::gamepad_axis_direction(GamepadAxis::LeftStickX, true).to_string(),
+            "LeftStickX+"
+        );
+        assert_eq!(
+            Binding::gamepad_axis_direction(GamepadAxis::LeftStickY, false).to_string(),
+            "LeftStickY-"
+        );
+        assert_eq!(Binding::AnyTouch.to_string(), "Any Touch");
+        assert_eq!(
+            Binding::TouchArea(Rect::new(0.0, 0.0, 1.0, 1.0)).to_string(),
+            "Touch Area"
+        );
+        assert_eq!(Binding::TouchDrag.to_string(), "Touch Drag");
+        assert_eq!(
+            Binding::Custom(CustomInput {
+                source: "MIDI".into(),
+                input: 7
+            })
+            .to_string(),
+            "MIDI 7"
+        );
         assert_eq!(
-            Binding::GamepadButton(GamepadButton::North).to_string(),
-            "North"
+            Binding::Action(Entity::from_raw_u32(5).unwrap()).to_string(),
+            "Action 5v0"
         );
     }
 }
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Gamepad stick, read as a whole by [`Binding::GamepadStick`](super::Binding::GamepadStick).
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadStick {
+    /// Corresponds to [`GamepadAxis::LeftStickX`] and [`GamepadAxis::LeftStickY`].
+    Left,
+    /// Corresponds to [`GamepadAxis::RightStickX`] and [`GamepadAxis::RightStickY`].
+    Right,
+}
+
+impl GamepadStick {
+    /// Returns X and Y axes of the stick.
+    #[must_use]
+    pub const fn axes(self) -> [GamepadAxis; 2] {
+        match self {
+            GamepadStick::Left => [GamepadAxis::LeftStickX, GamepadAxis::LeftStickY],
+            GamepadStick::Right => [GamepadAxis::RightStickX, GamepadAxis::RightStickY],
+        }
+    }
+}
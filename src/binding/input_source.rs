@@ -0,0 +1,247 @@
+/*!
+Custom input devices that can be bound to actions.
+
+Built-in bindings read Bevy input resources directly. To feed other devices, like
+MIDI controllers or inputs received over the network, implement [`InputSource`]
+for a resource, register it with [`InputSourceAppExt::add_input_source`]
+and bind its inputs with [`Binding::Custom`]. Sources can be disabled using [`CustomSources`].
+
+# Example
+
+```
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+
+#[derive(Resource, Default)]
+struct Midi {
+    /// Latest values for each control received from the device.
+    controls: HashMap<u32, f32>,
+}
+
+impl InputSource for Midi {
+    const NAME: &'static str = "MIDI";
+
+    fn value(&self, input: u32) -> ActionValue {
+        self.controls.get(&input).copied().unwrap_or_default().into()
+    }
+}
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .init_resource::<Midi>()
+    .add_input_source::<Midi>();
+
+app.world_mut().spawn((
+    Mixer,
+    actions!(Mixer[(
+        Action::<Volume>::new(),
+        bindings![Binding::custom::<Midi>(7)],
+    )]),
+));
+
+#[derive(Component)]
+struct Mixer;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Volume;
+```
+*/
+
+use alloc::borrow::Cow;
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::{context::input_reader, prelude::*};
+
+/// Extension trait for registering custom input sources.
+pub trait InputSourceAppExt {
+    /// Registers an input source, making its inputs accessible via [`Binding::Custom`].
+    ///
+    /// The source is read during [`EnhancedInputSystems::Prepare`]. If the resource
+    /// is missing, all its inputs will be captured as `false`.
+    fn add_input_source<S: InputSource>(&mut self) -> &mut Self;
+}
+
+impl InputSourceAppExt for App {
+    fn add_input_source<S: InputSource>(&mut self) -> &mut Self {
+        let mut bindings = self.world_mut().resource_mut::<CustomBindings>();
+        let registered = bindings.sources.insert(S::NAME);
+        debug_assert!(
+            registered,
+            "source `{}` shouldn't be added more than once",
+            S::NAME
+        );
+        if !registered {
+            return self;
+        }
+
+        self.add_systems(
+            PreUpdate,
+            read_source::<S>
+                .before(input_reader::update_pending)
+                .in_set(EnhancedInputSystems::Prepare),
+        )
+    }
+}
+
+/// Resource that provides values for [`Binding::Custom`].
+///
+/// See the module documentation for a usage example.
+pub trait InputSource: Resource {
+    /// Unique name of the source.
+    ///
+    /// Stored inside [`CustomInput`] to refer to this source.
+    const NAME: &'static str;
+
+    /// Returns the current value of the input with the given ID.
+    ///
+    /// Called once per frame for each bound input.
+    fn value(&self, input: u32) -> ActionValue;
+}
+
+/// Input from an [`InputSource`], read by [`Binding::Custom`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CustomInput {
+    /// Name of the source from [`InputSource::NAME`].
+    pub source: Cow<'static, str>,
+
+    /// Source-specific input ID.
+    pub input: u32,
+}
+
+impl CustomInput {
+    /// Creates a new instance for the given source and input ID.
+    #[must_use]
+    pub const fn new<S: InputSource>(input: u32) -> Self {
+        Self {
+            source: Cow::Borrowed(S::NAME),
+            input,
+        }
+    }
+}
+
+/// Configures which [`InputSource`]s are visible to actions.
+///
+/// All sources are enabled by default.
+///
+/// Like [`ActionSources`], can be inserted as a resource to apply globally, or as a component
+/// on a context entity to override the resource for all input contexts on this entity.
+#[derive(Resource, Component, Default, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, Resource)
+)]
+pub struct CustomSources {
+    /// Names of sources for which [`Binding::Custom`] is disabled.
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    disabled: HashSet<&'static str>,
+}
+
+impl CustomSources {
+    /// Enables or disables inputs from the source `S`.
+    pub fn set<S: InputSource>(&mut self, enabled: bool) {
+        if enabled {
+            self.disabled.remove(S::NAME);
+        } else {
+            self.disabled.insert(S::NAME);
+        }
+    }
+
+    /// Returns `true` if inputs from the source `S` are enabled.
+    #[must_use]
+    pub fn enabled<S: InputSource>(&self) -> bool {
+        self.enabled_by_name(S::NAME)
+    }
+
+    pub(crate) fn enabled_by_name(&self, source: &str) -> bool {
+        !self.disabled.contains(source)
+    }
+}
+
+/// Values of all bound inputs from registered sources.
+///
+/// Read by [`InputReader`](input_reader::InputReader) since sources are arbitrary resources.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(crate) struct SourceValues(HashMap<CustomInput, ActionValue>);
+
+/// Inputs bound via [`Binding::Custom`], grouped by source name.
+///
+/// Updated by [`Binding`] hooks, so sources don't need to scan all bindings every frame.
+#[derive(Resource, Default)]
+pub(crate) struct CustomBindings {
+    /// Names of sources registered via [`InputSourceAppExt::add_input_source`].
+    sources: HashSet<&'static str>,
+
+    /// Number of bindings for each input.
+    inputs: HashMap<Cow<'static, str>, HashMap<u32, usize>>,
+}
+
+impl CustomBindings {
+    pub(crate) fn add(&mut self, input: &CustomInput) {
+        *self
+            .inputs
+            .entry(input.source.clone())
+            .or_default()
+            .entry(input.input)
+            .or_default() += 1;
+    }
+
+    pub(crate) fn remove(&mut self, input: &CustomInput) {
+        let Some(inputs) = self.inputs.get_mut(&input.source) else {
+            return;
+        };
+        let Some(count) = inputs.get_mut(&input.input) else {
+            return;
+        };
+
+        *count -= 1;
+        if *count == 0 {
+            inputs.remove(&input.input);
+        }
+    }
+}
+
+fn read_source<S: InputSource>(
+    source: Option<Res<S>>,
+    bindings: Res<CustomBindings>,
+    mut values: ResMut<SourceValues>,
+) {
+    values.retain(|input, _| input.source != S::NAME);
+
+    let Some(source) = source else {
+        return;
+    };
+    let Some(inputs) = bindings.inputs.get(S::NAME) else {
+        return;
+    };
+
+    for &input in inputs.keys() {
+        values.insert(
+            CustomInput {
+                source: Cow::Borrowed(S::NAME),
+                input,
+            },
+            source.value(input),
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use core::fmt::{self, Display, Formatter};
+
+use bevy::prelude::*;
+use bitflags::bitflags;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Gamepad buttons that need to be held for a gamepad [`Binding`](super::Binding) to activate.
+///
+/// Allows creating shift layers, such as "hold left bumper and press South".
+///
+/// Works similarly to [`ModKeys`](super::mod_keys::ModKeys): the number of modifier buttons affects
+/// the order in which an action is evaluated, and consuming a binding also consumes its modifiers
+/// for other bindings that use them. See
+/// [`ActionSettings::consume_input`](crate::prelude::ActionSettings::consume_input)
+/// for more details.
+///
+/// Modifiers are read from the same gamepad as the bound input.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ModButtons(u32);
+
+bitflags! {
+    impl ModButtons: u32 {
+        /// Corresponds to [`GamepadButton::South`].
+        const SOUTH = 1 << 0;
+        /// Corresponds to [`GamepadButton::East`].
+        const EAST = 1 << 1;
+        /// Corresponds to [`GamepadButton::North`].
+        const NORTH = 1 << 2;
+        /// Corresponds to [`GamepadButton::West`].
+        const WEST = 1 << 3;
+        /// Corresponds to [`GamepadButton::C`].
+        const C = 1 << 4;
+        /// Corresponds to [`GamepadButton::Z`].
+        const Z = 1 << 5;
+        /// Corresponds to [`GamepadButton::LeftTrigger`].
+        const LEFT_TRIGGER = 1 << 6;
+        /// Corresponds to [`GamepadButton::LeftTrigger2`].
+        const LEFT_TRIGGER2 = 1 << 7;
+        /// Corresponds to [`GamepadButton::RightTrigger`].
+        const RIGHT_TRIGGER = 1 << 8;
+        /// Corresponds to [`GamepadButton::RightTrigger2`].
+        const RIGHT_TRIGGER2 = 1 << 9;
+        /// Corresponds to [`GamepadButton::Select`].
+        const SELECT = 1 << 10;
+        /// Corresponds to [`GamepadButton::Start`].
+        const START = 1 << 11;
+        /// Corresponds to [`GamepadButton::Mode`].
+        const MODE = 1 << 12;
+        /// Corresponds to [`GamepadButton::LeftThumb`].
+        const LEFT_THUMB = 1 << 13;
+        /// Corresponds to [`GamepadButton::RightThumb`].
+        const RIGHT_THUMB = 1 << 14;
+        /// Corresponds to [`GamepadButton::DPadUp`].
+        const DPAD_UP = 1 << 15;
+        /// Corresponds to [`GamepadButton::DPadDown`].
+        const DPAD_DOWN = 1 << 16;
+        /// Corresponds to [`GamepadButton::DPadLeft`].
+        const DPAD_LEFT = 1 << 17;
+        /// Corresponds to [`GamepadButton::DPadRight`].
+        const DPAD_RIGHT = 1 << 18;
+    }
+}
+
+/// Flags with their corresponding buttons.
+const BUTTONS: [(ModButtons, GamepadButton); 19] = [
+    (ModButtons::SOUTH, GamepadButton::South),
+    (ModButtons::EAST, GamepadButton::East),
+    (ModButtons::NORTH, GamepadButton::North),
+    (ModButtons::WEST, GamepadButton::West),
+    (ModButtons::C, GamepadButton::C),
+    (ModButtons::Z, GamepadButton::Z),
+    (ModButtons::LEFT_TRIGGER, GamepadButton::LeftTrigger),
+    (ModButtons::LEFT_TRIGGER2, GamepadButton::LeftTrigger2),
+    (ModButtons::RIGHT_TRIGGER, GamepadButton::RightTrigger),
+    (ModButtons::RIGHT_TRIGGER2, GamepadButton::RightTrigger2),
+    (ModButtons::SELECT, GamepadButton::Select),
+    (ModButtons::START, GamepadButton::Start),
+    (ModButtons::MODE, GamepadButton::Mode),
+    (ModButtons::LEFT_THUMB, GamepadButton::LeftThumb),
+    (ModButtons::RIGHT_THUMB, GamepadButton::RightThumb),
+    (ModButtons::DPAD_UP, GamepadButton::DPadUp),
+    (ModButtons::DPAD_DOWN, GamepadButton::DPadDown),
+    (ModButtons::DPAD_LEFT, GamepadButton::DPadLeft),
+    (ModButtons::DPAD_RIGHT, GamepadButton::DPadRight),
+];
+
+#[cfg(feature = "serialize")]
+impl Serialize for ModButtons {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bitflags::serde::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for ModButtons {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bitflags::serde::deserialize(deserializer)
+    }
+}
+
+impl Display for ModButtons {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, button) in self.iter_buttons().enumerate() {
+            if index != 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{button:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ModButtons {
+    /// Returns an instance with currently pressed buttons of the given gamepad.
+    #[must_use]
+    pub fn pressed(gamepad: &Gamepad) -> Self {
+        let mut mod_buttons = Self::empty();
+        for (flag, button) in BUTTONS {
+            if gamepad.pressed(button) {
+                mod_buttons |= flag;
+            }
+        }
+
+        mod_buttons
+    }
+
+    /// Returns an iterator over the buttons corresponding to the set bits.
+    pub fn iter_buttons(self) -> impl Iterator<Item = GamepadButton> {
+        BUTTONS
+            .into_iter()
+            .filter(move |&(flag, _)| self.contains(flag))
+            .map(|(_, button)| button)
+    }
+}
+
+impl From<GamepadButton> for ModButtons {
+    /// Converts button into a flag.
+    ///
+    /// Returns [`ModButtons::empty`] for [`GamepadButton::Other`].
+    fn from(value: GamepadButton) -> Self {
+        BUTTONS
+            .into_iter()
+            .find(|&(_, button)| button == value)
+            .map(|(flag, _)| flag)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn pressed_mod_buttons() {
+        let mut gamepad = Gamepad::default();
+        gamepad.digital_mut().press(GamepadButton::LeftTrigger);
+        gamepad.digital_mut().press(GamepadButton::South);
+
+        let mod_buttons = ModButtons::pressed(&gamepad);
+        assert_eq!(mod_buttons, ModButtons::LEFT_TRIGGER | ModButtons::SOUTH);
+    }
+
+    #[test]
+    fn mod_buttons_display() {
+        assert_eq!(ModButtons::LEFT_TRIGGER.to_string(), "LeftTrigger");
+        assert_eq!(
+            (ModButtons::LEFT_TRIGGER | ModButtons::RIGHT_TRIGGER2).to_string(),
+            "LeftTrigger + RightTrigger2"
+        );
+        assert_eq!(ModButtons::empty().to_string(), "");
+    }
+
+    #[test]
+    fn from_button() {
+        assert_eq!(
+            ModButtons::from(GamepadButton::DPadLeft),
+            ModButtons::DPAD_LEFT
+        );
+        assert_eq!(
+            ModButtons::from(GamepadButton::Other(0)),
+            ModButtons::empty()
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn mod_buttons_serde() {
+        assert_eq!(
+            ron::to_string(&(ModButtons::LEFT_TRIGGER | ModButtons::SOUTH)).unwrap(),
+            "\"SOUTH | LEFT_TRIGGER\""
+        );
+
+        let parsed: ModButtons = ron::from_str("\"RIGHT_TRIGGER\"").unwrap();
+        assert_eq!(parsed, ModButtons::RIGHT_TRIGGER);
+    }
+}
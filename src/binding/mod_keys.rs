@@ -5,7 +5,10 @@ use bitflags::bitflags;
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-/// Keyboard modifiers for both left and right keys.
+/// Keyboard modifiers.
+///
+/// Side-agnostic flags like [`Self::CONTROL`] are satisfied by either the left or the right key,
+/// while side-specific flags like [`Self::CONTROL_LEFT`] require the corresponding key.
 ///
 /// The number of keyboard modifiers in a [`Binding`](super::Binding) affects the
 /// order in which its action is evaluated. See
@@ -26,16 +29,52 @@ pub struct ModKeys(u8);
 bitflags! {
     impl ModKeys: u8 {
         /// Corresponds to [`KeyCode::ControlLeft`] and [`KeyCode::ControlRight`].
-        const CONTROL = 0b00000001;
+        const CONTROL = Self::CONTROL_LEFT.bits() | Self::CONTROL_RIGHT.bits();
         /// Corresponds to [`KeyCode::ShiftLeft`] and [`KeyCode::ShiftRight`]
-        const SHIFT = 0b00000010;
+        const SHIFT = Self::SHIFT_LEFT.bits() | Self::SHIFT_RIGHT.bits();
         /// Corresponds to [`KeyCode::AltLeft`] and [`KeyCode::AltRight`].
-        const ALT = 0b00000100;
+        const ALT = Self::ALT_LEFT.bits() | Self::ALT_RIGHT.bits();
         /// Corresponds to [`KeyCode::SuperLeft`] and [`KeyCode::SuperRight`].
-        const SUPER = 0b00001000;
+        const SUPER = Self::SUPER_LEFT.bits() | Self::SUPER_RIGHT.bits();
+        /// Corresponds to [`KeyCode::ControlLeft`].
+        const CONTROL_LEFT = 0b00000001;
+        /// Corresponds to [`KeyCode::ShiftLeft`].
+        const SHIFT_LEFT = 0b00000010;
+        /// Corresponds to [`KeyCode::AltLeft`].
+        const ALT_LEFT = 0b00000100;
+        /// Corresponds to [`KeyCode::SuperLeft`].
+        const SUPER_LEFT = 0b00001000;
+        /// Corresponds to [`KeyCode::ControlRight`].
+        const CONTROL_RIGHT = 0b00010000;
+        /// Corresponds to [`KeyCode::ShiftRight`].
+        const SHIFT_RIGHT = 0b00100000;
+        /// Corresponds to [`KeyCode::AltRight`].
+        const ALT_RIGHT = 0b01000000;
+        /// Corresponds to [`KeyCode::SuperRight`].
+        const SUPER_RIGHT = 0b10000000;
     }
 }
 
+/// Side-agnostic modifiers with their display names and left and right keys.
+const MODIFIERS: [(ModKeys, &str, [KeyCode; 2]); 4] = [
+    (
+        ModKeys::CONTROL,
+        "Ctrl",
+        [KeyCode::ControlLeft, KeyCode::ControlRight],
+    ),
+    (
+        ModKeys::SHIFT,
+        "Shift",
+        [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+    ),
+    (ModKeys::ALT, "Alt", [KeyCode::AltLeft, KeyCode::AltRight]),
+    (
+        ModKeys::SUPER,
+        "Super",
+        [KeyCode::SuperLeft, KeyCode::SuperRight],
+    ),
+];
+
 #[cfg(feature = "serialize")]
 impl Serialize for ModKeys {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -52,16 +91,14 @@ impl<'de> Deserialize<'de> for ModKeys {
 
 impl Display for ModKeys {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        for (index, (_, mod_key)) in self.iter_names().enumerate() {
+        for (index, (side, name, _)) in self.iter_sides().enumerate() {
             if index != 0 {
                 write!(f, " + ")?;
             }
-            match mod_key {
-                ModKeys::CONTROL => write!(f, "Ctrl")?,
-                ModKeys::SHIFT => write!(f, "Shift")?,
-                ModKeys::ALT => write!(f, "Alt")?,
-                ModKeys::SUPER => write!(f, "Super")?,
-                _ => unreachable!("iteration should yield only named flags"),
+            match side {
+                Side::Any => write!(f, "{name}")?,
+                Side::Left => write!(f, "Left {name}")?,
+                Side::Right => write!(f, "Right {name}")?,
             }
         }
 
@@ -71,12 +108,29 @@ impl Display for ModKeys {
 
 impl ModKeys {
     /// Returns an instance with currently active modifiers.
+    ///
+    /// Always uses side-agnostic flags.
     #[must_use]
     pub fn pressed(keys: &ButtonInput<KeyCode>) -> Self {
         let mut mod_keys = Self::empty();
-        for [key1, key2] in Self::all().iter_keys() {
-            if keys.any_pressed([key1, key2]) {
-                mod_keys |= key1.into();
+        for (mod_key, _, keys_pair) in MODIFIERS {
+            if keys.any_pressed(keys_pair) {
+                mod_keys |= mod_key;
+            }
+        }
+
+        mod_keys
+    }
+
+    /// Returns side-specific modifiers for which `pressed` returns `true`.
+    pub(crate) fn pressed_sides(pressed: impl Fn(KeyCode) -> bool) -> Self {
+        let mut mod_keys = Self::empty();
+        for (mod_key, _, [left, right]) in MODIFIERS {
+            if pressed(left) {
+                mod_keys |= mod_key & Self::LEFT;
+            }
+            if pressed(right) {
+                mod_keys |= mod_key & Self::RIGHT;
             }
         }
 
@@ -85,20 +139,51 @@ impl ModKeys {
 
     /// Returns an iterator over the key codes corresponding to the set modifier bits.
     ///
-    /// Each item contains left and right key codes.
-    pub fn iter_keys(self) -> impl Iterator<Item = [KeyCode; 2]> {
-        self.iter_names().map(|(_, mod_key)| match mod_key {
-            ModKeys::CONTROL => [KeyCode::ControlLeft, KeyCode::ControlRight],
-            ModKeys::SHIFT => [KeyCode::ShiftLeft, KeyCode::ShiftRight],
-            ModKeys::ALT => [KeyCode::AltLeft, KeyCode::AltRight],
-            ModKeys::SUPER => [KeyCode::SuperLeft, KeyCode::SuperRight],
-            _ => unreachable!("iteration should yield only named flags"),
+    /// Each item contains key codes, any of which satisfies the modifier: left and right
+    /// key codes for side-agnostic modifiers, or a single key code for side-specific ones.
+    pub fn iter_keys(self) -> impl Iterator<Item = &'static [KeyCode]> {
+        self.iter_sides().map(|(side, _, keys)| match side {
+            Side::Any => keys,
+            Side::Left => &keys[..1],
+            Side::Right => &keys[1..],
+        })
+    }
+
+    /// Returns an iterator over the set modifiers with their side, name and keys.
+    fn iter_sides(self) -> impl Iterator<Item = (Side, &'static str, &'static [KeyCode; 2])> {
+        MODIFIERS.iter().filter_map(move |(mod_key, name, keys)| {
+            let left = (self & *mod_key & Self::LEFT).bits() != 0;
+            let right = (self & *mod_key & Self::RIGHT).bits() != 0;
+            let side = match (left, right) {
+                (true, true) => Side::Any,
+                (true, false) => Side::Left,
+                (false, true) => Side::Right,
+                (false, false) => return None,
+            };
+            Some((side, *name, keys))
         })
     }
+
+    const LEFT: Self = Self::CONTROL_LEFT
+        .union(Self::SHIFT_LEFT)
+        .union(Self::ALT_LEFT)
+        .union(Self::SUPER_LEFT);
+
+    const RIGHT: Self = Self::CONTROL_RIGHT
+        .union(Self::SHIFT_RIGHT)
+        .union(Self::ALT_RIGHT)
+        .union(Self::SUPER_RIGHT);
+}
+
+/// Which keys satisfy a modifier.
+enum Side {
+    Any,
+    Left,
+    Right,
 }
 
 impl From<KeyCode> for ModKeys {
-    /// Converts key into a named modifier
+    /// Converts key into a named side-agnostic modifier.
     ///
     /// Returns [`ModKeys::empty`] if the key is not a modifier.
     fn from(value: KeyCode) -> Self {
@@ -114,7 +199,7 @@ impl From<KeyCode> for ModKeys {
 
 #[cfg(test)]
 mod tests {
-    use alloc::string::ToString;
+    use alloc::{string::ToString, vec::Vec};
 
     use super::*;
 
@@ -134,6 +219,25 @@ mod tests {
         assert_eq!(ModKeys::CONTROL.to_string(), "Ctrl");
         assert_eq!(ModKeys::all().to_string(), "Ctrl + Shift + Alt + Super");
         assert_eq!(ModKeys::empty().to_string(), "");
+        assert_eq!(ModKeys::CONTROL_LEFT.to_string(), "Left Ctrl");
+        assert_eq!(
+            (ModKeys::SHIFT | ModKeys::CONTROL_RIGHT).to_string(),
+            "Right Ctrl + Shift"
+        );
+    }
+
+    #[test]
+    fn iter_keys() {
+        let keys: Vec<_> = (ModKeys::CONTROL | ModKeys::ALT_RIGHT)
+            .iter_keys()
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                &[KeyCode::ControlLeft, KeyCode::ControlRight][..],
+                &[KeyCode::AltRight]
+            ]
+        );
     }
 
     #[cfg(feature = "serialize")]
@@ -148,5 +252,12 @@ mod tests {
 
         let parsed: ModKeys = ron::from_str("\"CONTROL | SHIFT\"").unwrap();
         assert_eq!(parsed, ModKeys::CONTROL | ModKeys::SHIFT);
+
+        assert_eq!(
+            ron::to_string(&(ModKeys::CONTROL | ModKeys::SHIFT_LEFT)).unwrap(),
+            "\"CONTROL | SHIFT_LEFT\""
+        );
+        let parsed: ModKeys = ron::from_str("\"ALT_RIGHT\"").unwrap();
+        assert_eq!(parsed, ModKeys::ALT_RIGHT);
     }
 }
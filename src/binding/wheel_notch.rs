@@ -0,0 +1,279 @@
+/*!
+Discrete mouse wheel notches that can be bound to actions.
+
+[`Binding::MouseWheel`](super::Binding::MouseWheel) captures the scroll delta as
+[`ActionValue::Axis2D`](crate::prelude::ActionValue::Axis2D), which is convenient
+for zooming, but hard to use for discrete actions, such as cycling weapons.
+[`Binding::MouseWheelNotch`](super::Binding::MouseWheelNotch) instead captures one press per scrolled notch in the given
+[`WheelDirection`]. If multiple notches are scrolled at once, the extra presses are queued
+over the next frames.
+
+Pixel-based scrolling, typically from trackpads, is converted into lines using
+[`WheelNotchSettings::pixels_per_line`], with each line treated as a single notch.
+
+Notches are advanced once per frame. For contexts in schedules with input buffering,
+they're advanced once per schedule run instead.
+See [`InputContextAppExt::buffer_inputs_in`](crate::prelude::InputContextAppExt::buffer_inputs_in).
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .insert_resource(WheelNotchSettings {
+        pixels_per_line: 40.0,
+    });
+
+app.world_mut().spawn((
+    Player,
+    actions!(Player[
+        (Action::<NextWeapon>::new(), bindings![WheelDirection::Down]),
+        (Action::<PreviousWeapon>::new(), bindings![WheelDirection::Up]),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct NextWeapon;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct PreviousWeapon;
+```
+*/
+
+use bevy::{
+    input::mouse::{AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+};
+use log::trace;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Scroll direction, read by [`Binding::MouseWheelNotch`](super::Binding::MouseWheelNotch).
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WheelDirection {
+    /// Positive Y of [`AccumulatedMouseScroll::delta`].
+    Up,
+    /// Negative Y of [`AccumulatedMouseScroll::delta`].
+    Down,
+    /// Negative X of [`AccumulatedMouseScroll::delta`].
+    Left,
+    /// Positive X of [`AccumulatedMouseScroll::delta`].
+    Right,
+}
+
+/// Configures how scrolling is converted into notches for [`Binding::MouseWheelNotch`](super::Binding::MouseWheelNotch).
+#[derive(Resource)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default, Resource))]
+pub struct WheelNotchSettings {
+    /// Number of pixels that correspond to a single line for [`MouseScrollUnit::Pixel`].
+    ///
+    /// Should be positive. Each line is treated as a single notch.
+    ///
+    /// By default set to 20.0.
+    pub pixels_per_line: f32,
+}
+
+impl Default for WheelNotchSettings {
+    fn default() -> Self {
+        Self {
+            pixels_per_line: 20.0,
+        }
+    }
+}
+
+/// Notches pressed in this frame and queued for the next frames.
+#[derive(Resource, Default)]
+pub(crate) struct WheelNotches {
+    /// Scrolled distance in lines that hasn't yet formed a whole notch.
+    remainder: Vec2,
+
+    /// Number of presses waiting for each direction.
+    queued: [u32; 4],
+
+    /// Directions pressed in this frame.
+    pressed: [bool; 4],
+}
+
+impl WheelNotches {
+    pub(crate) fn pressed(&self, direction: WheelDirection) -> bool {
+        self.pressed[direction as usize]
+    }
+
+    /// Queues notches for the scrolled distance and presses the next queued notches.
+    ///
+    /// Should be called once per evaluation.
+    pub(crate) fn advance(&mut self, lines: Vec2) {
+        self.remainder += lines;
+        for (distance, positive, negative) in [
+            (
+                &mut self.remainder.x,
+                WheelDirection::Right,
+                WheelDirection::Left,
+            ),
+            (
+                &mut self.remainder.y,
+                WheelDirection::Up,
+                WheelDirection::Down,
+            ),
+        ] {
+            // Truncate towards zero to keep the fractional part for the next evaluations.
+            let count = *distance as i32;
+            if count == 0 {
+                continue;
+            }
+            *distance -= count as f32;
+
+            let (direction, opposite) = if count > 0 {
+                (positive, negative)
+            } else {
+                (negative, positive)
+            };
+
+            // Scrolling back cancels the notches that haven't been pressed yet.
+            self.queued[opposite as usize] = 0;
+            self.queued[direction as usize] += count.unsigned_abs();
+            trace!("queuing {count} notches for `{direction:?}`");
+        }
+
+        for (queued, pressed) in self.queued.iter_mut().zip(&mut self.pressed) {
+            if *pressed {
+                // Release for an evaluation between notches to register each as a separate press.
+                *pressed = false;
+            } else if *queued > 0 {
+                *queued -= 1;
+                *pressed = true;
+            }
+        }
+    }
+}
+
+/// Converts the scroll delta into lines.
+pub(crate) fn lines(settings: &WheelNotchSettings, unit: MouseScrollUnit, delta: Vec2) -> Vec2 {
+    match unit {
+        MouseScrollUnit::Line => delta,
+        MouseScrollUnit::Pixel => delta / settings.pixels_per_line,
+    }
+}
+
+pub(crate) fn update(
+    settings: Res<WheelNotchSettings>,
+    scroll: Option<Res<AccumulatedMouseScroll>>,
+    mut notches: ResMut<WheelNotches>,
+) {
+    let lines = scroll
+        .map(|scroll| lines(&settings, scroll.unit, scroll.delta))
+        .unwrap_or_default();
+
+    notches.advance(lines);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn line() {
+        let mut world = init_world();
+
+        scroll(&mut world, MouseScrollUnit::Line, Vec2::new(0.0, 2.0));
+        world.run_system_once(update).unwrap();
+        let notches = world.resource::<WheelNotches>();
+        assert!(notches.pressed(WheelDirection::Up));
+        assert!(!notches.pressed(WheelDirection::Down));
+
+        scroll(&mut world, MouseScrollUnit::Line, Vec2::ZERO);
+        world.run_system_once(update).unwrap();
+        assert!(
+            !world.resource::<WheelNotches>().pressed(WheelDirection::Up),
+            "should release between notches"
+        );
+
+        world.run_system_once(update).unwrap();
+        assert!(
+            world.resource::<WheelNotches>().pressed(WheelDirection::Up),
+            "second notch should be queued"
+        );
+
+        world.run_system_once(update).unwrap();
+        world.run_system_once(update).unwrap();
+        assert!(!world.resource::<WheelNotches>().pressed(WheelDirection::Up));
+    }
+
+    #[test]
+    fn pixel() {
+        let mut world = init_world();
+
+        scroll(&mut world, MouseScrollUnit::Pixel, Vec2::new(5.0, 0.0));
+        world.run_system_once(update).unwrap();
+        assert!(
+            !world
+                .resource::<WheelNotches>()
+                .pressed(WheelDirection::Right)
+        );
+
+        world.run_system_once(update).unwrap();
+        assert!(
+            world
+                .resource::<WheelNotches>()
+                .pressed(WheelDirection::Right),
+            "pixels should accumulate into a line"
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        let mut world = init_world();
+
+        scroll(&mut world, MouseScrollUnit::Line, Vec2::new(0.0, 3.0));
+        world.run_system_once(update).unwrap();
+        assert!(world.resource::<WheelNotches>().pressed(WheelDirection::Up));
+
+        scroll(&mut world, MouseScrollUnit::Line, Vec2::new(0.0, -1.0));
+        world.run_system_once(update).unwrap();
+        let notches = world.resource::<WheelNotches>();
+        assert!(!notches.pressed(WheelDirection::Up));
+        assert!(notches.pressed(WheelDirection::Down));
+
+        scroll(&mut world, MouseScrollUnit::Line, Vec2::ZERO);
+        world.run_system_once(update).unwrap();
+        world.run_system_once(update).unwrap();
+        assert!(
+            !world.resource::<WheelNotches>().pressed(WheelDirection::Up),
+            "queued notches should be canceled"
+        );
+    }
+
+    fn scroll(world: &mut World, unit: MouseScrollUnit, delta: Vec2) {
+        world.insert_resource(AccumulatedMouseScroll { unit, delta });
+    }
+
+    fn init_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(WheelNotchSettings {
+            pixels_per_line: 10.0,
+        });
+        world.init_resource::<WheelNotches>();
+
+        world
+    }
+}
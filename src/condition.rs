@@ -96,6 +96,11 @@ pub trait InputCondition: Debug {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Explicit
     }
+
+    /// Resets the internal state, such as timers.
+    ///
+    /// Called when actions are reset without input, for example after losing the window focus.
+    fn reset(&mut self) {}
 }
 
 /// Determines how a condition contributes to the final [`TriggerState`].
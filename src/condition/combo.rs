@@ -103,14 +103,6 @@ impl Combo {
         &self.timer
     }
 
-    fn reset(&mut self) {
-        self.step_index = 0;
-        self.timer.reset();
-
-        let duration = self.steps.first().map(|s| s.timeout).unwrap_or_default();
-        self.timer.set_duration(Duration::from_secs_f32(duration));
-    }
-
     fn is_cancelled(&self, actions: &ActionsQuery) -> bool {
         let current_step = &self.steps[self.step_index];
         for condition in &self.cancel_actions {
@@ -212,6 +204,14 @@ impl InputCondition for Combo {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Implicit
     }
+
+    fn reset(&mut self) {
+        self.step_index = 0;
+        self.timer.reset();
+
+        let duration = self.steps.first().map(|s| s.timeout).unwrap_or_default();
+        self.timer.set_duration(Duration::from_secs_f32(duration));
+    }
 }
 
 /// An action with associated events that progress [`Combo`].
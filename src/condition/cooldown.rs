@@ -87,6 +87,10 @@ impl InputCondition for Cooldown {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Implicit
     }
+
+    fn reset(&mut self) {
+        self.actuated = false;
+    }
 }
 
 #[cfg(test)]
@@ -9,12 +9,17 @@ use bevy::{
     prelude::*,
 };
 
-use crate::prelude::*;
+use crate::{
+    context::{ContextRegistry, setup_schedules},
+    prelude::*,
+};
 
 pub trait InputConditionAppExt {
     /// Registers an input condition, making it accessible during context evaluation.
     ///
     /// All built-in conditions are already registered.
+    ///
+    /// Can be called after [`App::finish`]. In this case, the evaluation systems will be rebuilt.
     fn add_input_condition<C: InputCondition + Component<Mutability = Mutable>>(
         &mut self,
     ) -> &mut Self;
@@ -30,7 +35,13 @@ impl InputConditionAppExt for App {
 
         self.add_observer(register_condition::<C>)
             .add_observer(unregister_condition::<C>)
-            .register_required_components::<C, ConditionFns>()
+            .register_required_components::<C, ConditionFns>();
+
+        if self.world().resource::<ContextRegistry>().finished {
+            setup_schedules(self, None);
+        }
+
+        self
     }
 }
 
@@ -57,8 +68,6 @@ fn unregister_condition<C: InputCondition + Component<Mutability = Mutable>>(
 /// IDs of all registered input conditions.
 ///
 /// Used to dynamically register access for [`FilteredEntityMut`].
-///
-/// Exists only during the plugin initialization.
 #[derive(Resource, Deref, Default)]
 pub(crate) struct ConditionRegistry(Vec<ComponentId>);
 
@@ -68,7 +77,7 @@ pub(crate) struct ConditionRegistry(Vec<ComponentId>);
 /// we dynamically get them as the trait from [`FilteredEntityMut`].
 ///
 /// Updated automatically using triggers.
-#[derive(Component, Deref, Default)]
+#[derive(Component, Deref, Default, Clone)]
 pub(crate) struct ConditionFns(Vec<GetConditionFn>);
 
 type GetConditionFn = for<'a> fn(&'a mut FilteredEntityMut) -> &'a mut dyn InputCondition;
@@ -86,6 +86,10 @@ impl InputCondition for Hold {
             TriggerState::None
         }
     }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+    }
 }
 
 #[cfg(test)]
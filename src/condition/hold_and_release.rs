@@ -72,6 +72,10 @@ impl InputCondition for HoldAndRelease {
             }
         }
     }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+    }
 }
 
 #[cfg(test)]
@@ -53,6 +53,10 @@ impl InputCondition for Press {
             TriggerState::None
         }
     }
+
+    fn reset(&mut self) {
+        self.actuated = false;
+    }
 }
 
 #[cfg(test)]
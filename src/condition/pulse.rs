@@ -142,16 +142,20 @@ impl InputCondition for Pulse {
                 TriggerState::None
             }
         } else {
-            if let Some(initial_delay) = self.initial_delay {
-                self.timer
-                    .set_duration(Duration::from_secs_f32(initial_delay));
-            }
-            self.timer.reset();
-            self.trigger_count = 0;
-            self.started_actuation = false;
+            self.reset();
             TriggerState::None
         }
     }
+
+    fn reset(&mut self) {
+        if let Some(initial_delay) = self.initial_delay {
+            self.timer
+                .set_duration(Duration::from_secs_f32(initial_delay));
+        }
+        self.timer.reset();
+        self.trigger_count = 0;
+        self.started_actuation = false;
+    }
 }
 
 #[cfg(test)]
@@ -56,6 +56,10 @@ impl InputCondition for Release {
             TriggerState::None
         }
     }
+
+    fn reset(&mut self) {
+        self.actuated = false;
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +80,11 @@ impl InputCondition for Tap {
             TriggerState::None
         }
     }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        self.actuated = false;
+    }
 }
 
 #[cfg(test)]
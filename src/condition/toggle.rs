@@ -69,6 +69,11 @@ impl InputCondition for Toggle {
             TriggerState::None
         }
     }
+
+    fn reset(&mut self) {
+        self.toggled = false;
+        self.actuated = false;
+    }
 }
 
 #[cfg(test)]
@@ -5,10 +5,12 @@ They allow you to define when actions are active and which inputs they respond t
 Actions are checked only if their context is active,
 and are evaluated in the order of their context's [`ContextPriority`],
 then mainly by the order in which the actions were added to the context,
-with the first action having the highest priority.
+with the first action having the highest priority. Actions that read other actions
+via [`Binding::Action`] are always evaluated after the actions they reference.
 
 Further details on how to order actions due to their inputs being consumed
 can be found in the documentation for [`ActionSettings::consume_input`].
+To block inputs from lower-priority contexts regardless of action states, use [`ContextBlocking`].
 
 # Removing contexts
 
@@ -57,14 +59,19 @@ triggering the corresponding events. Depending on your use case, using [`Context
 
 pub mod input_reader;
 mod instance;
+pub mod stack;
 pub mod time;
 mod trigger_tracker;
+#[cfg(feature = "window")]
+pub mod window_focus;
 
 #[cfg(feature = "reflect")]
 use core::any::type_name;
 use core::{
     any::TypeId,
     cmp::{Ordering, Reverse},
+    fmt::{self, Debug, Formatter},
+    iter,
     marker::PhantomData,
 };
 
@@ -72,23 +79,28 @@ use core::{
 use bevy::ecs::system::SystemState;
 #[cfg(feature = "reflect")]
 use bevy::reflect::utility::GenericTypePathCell;
+#[cfg(feature = "window")]
+use bevy::window::WindowFocused;
 use bevy::{
     ecs::{
         component::ComponentId,
+        entity::EntityHashMap,
         entity_disabling::Disabled,
-        schedule::ScheduleLabel,
-        system::{ParamBuilder, QueryParamBuilder},
+        schedule::{InternedScheduleLabel, ScheduleCleanupPolicy, ScheduleLabel},
+        system::{LocalBuilder, ParamBuilder, QueryParamBuilder},
         world::{FilteredEntityMut, FilteredEntityRef},
     },
+    platform::collections::HashMap,
     prelude::*,
 };
+use bitflags::bitflags;
 use log::{debug, trace};
 #[cfg(feature = "serialize")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
     action::fns::ActionFns,
-    binding::FirstActivation,
+    binding::{BindingChanges, FirstActivation},
     condition::fns::{ConditionFns, ConditionRegistry},
     context::{input_reader::PendingBindings, trigger_tracker::TriggerTracker},
     modifier::fns::{ModifierFns, ModifierRegistry},
@@ -130,7 +142,21 @@ pub trait InputContextAppExt {
     /// For example, if your game logic runs inside [`FixedMain`](bevy::app::FixedMain), you can set the schedule
     /// to [`FixedPreUpdate`]. This way, if the schedule runs multiple times per frame, events like [`Start`] or
     /// [`Complete`] will be triggered only once per schedule run.
+    ///
+    /// Can be called after [`App::finish`], for example by plugins that are loaded at runtime.
+    /// In this case, the evaluation systems for the schedule will be rebuilt.
     fn add_input_context_to<S: ScheduleLabel + Default, C: Component>(&mut self) -> &mut Self;
+
+    /// Enables input buffering for contexts evaluated in schedule `S`.
+    ///
+    /// Intended for schedules inside [`FixedMain`](bevy::app::FixedMain). By default, per-frame mouse motion
+    /// and scroll deltas are read whole on every schedule run, so they're counted multiple times if
+    /// the schedule runs multiple times per frame and lost if it doesn't run. With buffering, deltas are
+    /// accumulated across frames and split evenly across the schedule runs, so mouse-driven actions, like
+    /// camera look, behave the same at any frame rate. [`Binding::MouseWheelNotch`] is also advanced per run.
+    ///
+    /// Buttons pressed and released between runs are captured regardless of this setting.
+    fn buffer_inputs_in<S: ScheduleLabel + Default>(&mut self) -> &mut Self;
 }
 
 impl InputContextAppExt for App {
@@ -143,24 +169,18 @@ impl InputContextAppExt for App {
 
         let actions_id = self.world_mut().register_component::<Actions<C>>();
         let activity_id = self.world_mut().register_component::<ContextActivity<C>>();
+        let blocking_id = self.world_mut().register_component::<ContextBlocking<C>>();
         let mut registry = self.world_mut().resource_mut::<ContextRegistry>();
-        if let Some(contexts) = registry
-            .iter_mut()
-            .find(|c| c.schedule_id == TypeId::of::<S>())
-        {
-            debug_assert!(
-                !contexts.actions_ids.contains(&actions_id),
-                "context `{}` shouldn't be added more than once",
-                ShortName::of::<C>()
-            );
-            contexts.actions_ids.push(actions_id);
-            contexts.activity_ids.push(activity_id);
-        } else {
-            let mut contexts = ScheduleContexts::new::<S>();
-            contexts.actions_ids.push(actions_id);
-            contexts.activity_ids.push(activity_id);
-            registry.push(contexts);
-        }
+        let contexts = registry.schedule_mut::<S>();
+        debug_assert!(
+            !contexts.actions_ids.contains(&actions_id),
+            "context `{}` shouldn't be added more than once",
+            ShortName::of::<C>()
+        );
+        contexts.actions_ids.push(actions_id);
+        contexts.activity_ids.push(activity_id);
+        contexts.blocking_ids.push(blocking_id);
+        let finished = registry.finished;
 
         let _ = self.try_register_required_components::<C, ContextPriority<C>>();
         let _ = self.try_register_required_components::<C, ContextActivity<C>>();
@@ -170,14 +190,42 @@ impl InputContextAppExt for App {
             self.register_type::<ActionOf<C>>();
             self.register_type::<Actions<C>>();
             self.register_type::<ContextActivity<C>>();
+            self.register_type::<ContextBlocking<C>>();
             self.register_type::<ContextPriority<C>>();
         }
 
         self.add_observer(register::<C, S>)
             .add_observer(unregister::<C, S>)
-            .add_observer(deactivate::<C>)
+            .add_observer(replace_activity::<C>)
+            .add_observer(insert_activity::<C>)
+            .add_observer(remove_activity::<C>)
             .add_observer(reset_action::<C>);
 
+        #[cfg(feature = "window")]
+        self.add_systems(
+            PreUpdate,
+            window_focus::update::<C>
+                .run_if(on_message::<WindowFocused>)
+                .before(input_reader::update_pending)
+                .in_set(EnhancedInputSystems::Prepare),
+        );
+
+        if finished {
+            setup_schedules(self, Some(TypeId::of::<S>()));
+        }
+
+        self
+    }
+
+    fn buffer_inputs_in<S: ScheduleLabel + Default>(&mut self) -> &mut Self {
+        debug!("enabling input buffering for `{}`", ShortName::of::<S>());
+
+        let mut registry = self.world_mut().resource_mut::<ContextRegistry>();
+        registry.schedule_mut::<S>().buffered = true;
+        if registry.finished {
+            setup_schedules(self, Some(TypeId::of::<S>()));
+        }
+
         self
     }
 }
@@ -190,20 +238,52 @@ impl InputContextAppExt for App {
 /// This resource stores registered contexts per-schedule in a type-erased way
 /// to perform the setup after all registrations in [`App::finish`].
 ///
-/// Exists only during the plugin initialization.
+/// Contexts registered after [`App::finish`] rebuild the systems for their schedule.
 #[derive(Resource, Default, Deref, DerefMut)]
-pub(crate) struct ContextRegistry(Vec<ScheduleContexts>);
+pub(crate) struct ContextRegistry {
+    #[deref]
+    schedules: Vec<ScheduleContexts>,
+
+    /// Whether the initial setup was performed.
+    pub(crate) finished: bool,
+}
+
+impl ContextRegistry {
+    /// Returns contexts for schedule `S`, inserting an empty entry if missing.
+    fn schedule_mut<S: ScheduleLabel + Default>(&mut self) -> &mut ScheduleContexts {
+        let index = match self.iter().position(|c| c.schedule_id == TypeId::of::<S>()) {
+            Some(index) => index,
+            None => {
+                self.push(ScheduleContexts::new::<S>());
+                self.len() - 1
+            }
+        };
+
+        &mut self[index]
+    }
+}
 
 pub(crate) struct ScheduleContexts {
     /// Schedule ID for which all actions were registered.
     schedule_id: TypeId,
 
+    /// Label of the schedule to remove systems on rebuild.
+    label: InternedScheduleLabel,
+
     /// IDs of [`Actions<C>`].
     actions_ids: Vec<ComponentId>,
 
     /// IDs of [`ContextActivity<C>`].
     activity_ids: Vec<ComponentId>,
 
+    /// IDs of [`ContextBlocking<C>`].
+    blocking_ids: Vec<ComponentId>,
+
+    /// Whether inputs should be buffered across schedule runs.
+    ///
+    /// See [`InputContextAppExt::buffer_inputs_in`].
+    buffered: bool,
+
     /// Configures the app for this schedule.
     setup: fn(&Self, &mut App, &ConditionRegistry, &ModifierRegistry),
 }
@@ -216,8 +296,11 @@ impl ScheduleContexts {
     fn new<S: ScheduleLabel + Default>() -> Self {
         Self {
             schedule_id: TypeId::of::<S>(),
+            label: S::default().intern(),
             actions_ids: Default::default(),
             activity_ids: Default::default(),
+            blocking_ids: Default::default(),
+            buffered: false,
             // Since the type is not present in the function signature, we can store
             // functions for specific type without making the struct generic.
             setup: Self::setup_typed::<S>,
@@ -244,21 +327,30 @@ impl ScheduleContexts {
         debug!("setting up systems for `{}`", ShortName::of::<S>());
 
         let update_fn = (
+            ParamBuilder,
+            LocalBuilder(self.buffered),
+            ParamBuilder,
             ParamBuilder,
             ParamBuilder,
             ParamBuilder,
             ParamBuilder,
             QueryParamBuilder::new(|builder| {
-                builder
-                    .data::<Option<&GamepadDevice>>()
-                    .optional(|builder| {
-                        for &id in &self.activity_ids {
-                            builder.mut_id(id);
-                        }
-                        for &id in &self.actions_ids {
-                            builder.mut_id(id);
-                        }
-                    });
+                builder.data::<Option<&GamepadDevice>>();
+                #[cfg(feature = "window")]
+                builder.data::<Option<&ContextWindow>>();
+                #[cfg(feature = "input_focus")]
+                builder.data::<Option<&InputFocusPolicy>>();
+                builder.optional(|builder| {
+                    for &id in &self.activity_ids {
+                        builder.mut_id(id);
+                    }
+                    for &id in &self.blocking_ids {
+                        builder.ref_id(id);
+                    }
+                    for &id in &self.actions_ids {
+                        builder.mut_id(id);
+                    }
+                });
             }),
             ParamBuilder,
             ParamBuilder,
@@ -305,17 +397,72 @@ impl ScheduleContexts {
                 (
                     update_fn.in_set(EnhancedInputSystems::Update),
                     trigger_fn.in_set(EnhancedInputSystems::Apply),
-                ),
+                )
+                    .in_set(ContextSystems),
             );
     }
 }
 
+/// Evaluation systems added by [`ScheduleContexts::setup`].
+#[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash)]
+struct ContextSystems;
+
+/// Sets up evaluation systems for all registered schedules or only for the one with the given ID.
+///
+/// Systems that were already set up are replaced, so they can access newly registered
+/// contexts, conditions and modifiers.
+pub(crate) fn setup_schedules(app: &mut App, schedule_id: Option<TypeId>) {
+    let world = app.world_mut();
+    let registry = world
+        .remove_resource::<ContextRegistry>()
+        .expect("contexts registry should be inserted in `build`");
+    let conditions = world
+        .remove_resource::<ConditionRegistry>()
+        .expect("conditions registry should be inserted in `build`");
+    let modifiers = world
+        .remove_resource::<ModifierRegistry>()
+        .expect("modifiers registry should be inserted in `build`");
+
+    for contexts in registry
+        .iter()
+        .filter(|contexts| schedule_id.is_none_or(|id| contexts.schedule_id == id))
+    {
+        if registry.finished && app.get_schedule(contexts.label).is_some() {
+            debug!(
+                "removing systems from `{:?}` to rebuild them",
+                contexts.label
+            );
+            // Fails if the schedule has no contexts yet.
+            let _ = app.remove_systems_in_set(
+                contexts.label,
+                ContextSystems,
+                ScheduleCleanupPolicy::RemoveSystemsOnly,
+            );
+        }
+        contexts.setup(app, &conditions, &modifiers);
+    }
+
+    let world = app.world_mut();
+    world.insert_resource(registry);
+    world.insert_resource(conditions);
+    world.insert_resource(modifiers);
+}
+
 fn register<C: Component, S: ScheduleLabel>(
     insert: On<Insert, ContextPriority<C>>,
     mut instances: ResMut<ContextInstances<S>>,
     contexts: Query<&ContextPriority<C>, Allow<Disabled>>,
 ) {
     let priority = **contexts.get(insert.entity).unwrap();
+    if instances.reorder::<C>(insert.entity, priority) {
+        debug!(
+            "changing priority of `{}` on `{}` to {priority}",
+            ShortName::of::<C>(),
+            insert.entity
+        );
+        return;
+    }
+
     debug!(
         "registering `{}` to `{}` with priority {priority}",
         ShortName::of::<C>(),
@@ -326,43 +473,82 @@ fn register<C: Component, S: ScheduleLabel>(
 }
 
 fn unregister<C: Component, S: ScheduleLabel>(
-    replace: On<Replace, ContextPriority<C>>,
+    remove: On<Remove, ContextPriority<C>>,
     mut instances: ResMut<ContextInstances<S>>,
 ) {
     debug!(
         "unregistering `{}` from `{}`",
         ShortName::of::<C>(),
-        replace.entity,
+        remove.entity,
     );
-    instances.remove::<C>(replace.entity);
+    instances.remove::<C>(remove.entity);
+}
+
+fn replace_activity<C: Component>(
+    replace: On<Replace, ContextActivity<C>>,
+    mut replaced: ResMut<ReplacedActivity>,
+    contexts: Query<&ContextActivity<C>>,
+) {
+    if let Ok(activity) = contexts.get(replace.entity) {
+        replaced.insert((replace.entity, TypeId::of::<C>()), **activity);
+    }
 }
 
-fn deactivate<C: Component>(
+fn insert_activity<C: Component>(
     insert: On<Insert, ContextActivity<C>>,
+    mut commands: Commands,
+    mut replaced: ResMut<ReplacedActivity>,
     mut pending: ResMut<PendingBindings>,
-    contexts: Query<(&ContextActivity<C>, &Actions<C>)>,
+    contexts: Query<(&ContextActivity<C>, Option<&Actions<C>>)>,
     actions: Query<(&ActionSettings, &Bindings)>,
     bindings: Query<&Binding>,
 ) {
-    let Ok((&active, context_actions)) = contexts.get(insert.entity) else {
+    let Ok((&activity, context_actions)) = contexts.get(insert.entity) else {
         return;
     };
 
     debug!(
         "setting activity of `{}` to `{}`",
         ShortName::of::<C>(),
-        *active,
+        *activity,
     );
 
-    if !*active {
+    let previous = replaced.remove(&(insert.entity, TypeId::of::<C>()));
+    if previous != Some(*activity) {
+        if *activity {
+            let reason = if previous.is_some() {
+                activity.reason
+            } else {
+                ActivityReason::Added
+            };
+            commands.trigger(ContextActivated::<C>::new(insert.entity, reason));
+        } else if previous.is_some() {
+            commands.trigger(ContextDeactivated::<C>::new(insert.entity, activity.reason));
+        }
+    }
+
+    if !*activity && let Some(context_actions) = context_actions {
         for (settings, action_bindings) in actions.iter_many(context_actions) {
             if settings.require_reset {
-                pending.extend(bindings.iter_many(action_bindings).copied());
+                pending.extend(bindings.iter_many(action_bindings).cloned());
             }
         }
     }
 }
 
+fn remove_activity<C: Component>(
+    remove: On<Remove, ContextActivity<C>>,
+    mut commands: Commands,
+    mut replaced: ResMut<ReplacedActivity>,
+) {
+    if replaced.remove(&(remove.entity, TypeId::of::<C>())) == Some(true) {
+        commands.trigger(ContextDeactivated::<C>::new(
+            remove.entity,
+            ActivityReason::Removed,
+        ));
+    }
+}
+
 /// Resets action data and triggers corresponding events on removal.
 pub(crate) fn reset_action<C: Component>(
     remove: On<Remove, ActionOf<C>>,
@@ -370,6 +556,7 @@ pub(crate) fn reset_action<C: Component>(
     mut pending: ResMut<PendingBindings>,
     mut actions: Query<(
         &ActionOf<C>,
+        &Name,
         &ActionSettings,
         &ActionFns,
         Option<&Bindings>,
@@ -380,8 +567,17 @@ pub(crate) fn reset_action<C: Component>(
     )>,
     bindings: Query<&Binding>,
 ) {
-    let Ok((action_of, settings, fns, action_bindings, mut value, mut state, mut events, mut time)) =
-        actions.get_mut(remove.entity)
+    let Ok((
+        action_of,
+        name,
+        settings,
+        fns,
+        action_bindings,
+        mut value,
+        mut state,
+        mut events,
+        mut time,
+    )) = actions.get_mut(remove.entity)
     else {
         trace!("ignoring reset for `{}`", remove.entity);
         return;
@@ -396,6 +592,7 @@ pub(crate) fn reset_action<C: Component>(
         &mut commands,
         **action_of,
         remove.entity,
+        name,
         *state,
         *events,
         *value,
@@ -405,10 +602,32 @@ pub(crate) fn reset_action<C: Component>(
     if let Some(action_bindings) = action_bindings
         && settings.require_reset
     {
-        pending.extend(bindings.iter_many(action_bindings).copied());
+        pending.extend(bindings.iter_many(action_bindings).cloned());
     }
 }
 
+/// Inserts [`ContextActivity<C>`] with the given value if it differs from the current one.
+pub(crate) fn set_context_activity<C: Component>(
+    commands: &mut Commands,
+    activity: &Query<&ContextActivity<C>>,
+    entity: Entity,
+    active: bool,
+    reason: ActivityReason,
+) {
+    if let Ok(current) = activity.get(entity)
+        && **current == active
+    {
+        return;
+    }
+    debug!(
+        "setting `{}` on `{entity}` to `{active}`",
+        ShortName::of::<C>(),
+    );
+    commands
+        .entity(entity)
+        .insert(ContextActivity::<C>::new(active).with_reason(reason));
+}
+
 /// Marks an [`Action<C>`] as manually mocked, skipping the [`EnhancedInputSystems::Update`] logic for it.
 ///
 /// This allows modifying any action data without its values being overridden during evaluation.
@@ -419,10 +638,13 @@ pub struct ExternallyMocked;
 
 #[allow(clippy::too_many_arguments)]
 fn update<S: ScheduleLabel>(
-    mut consume_buffer: Local<Vec<Binding>>, // Consumed inputs during state evaluation.
+    mut consume_buffer: Local<Vec<(Binding, GamepadDevice)>>, // Consumed inputs during state evaluation.
+    buffered: Local<bool>,
     time: ContextTime,
     mut reader: InputReader,
     instances: Res<ContextInstances<S>>,
+    binding_changes: Res<BindingChanges>,
+    mut depths: Local<EntityHashMap<Option<usize>>>, // Cached depths of actions, see `action_depth`.
     mut contexts: Query<FilteredEntityMut>,
     mut actions: Query<
         (
@@ -446,6 +668,7 @@ fn update<S: ScheduleLabel>(
         (
             Entity,
             &Binding,
+            Option<&GamepadDevice>,
             &mut FirstActivation,
             Option<&ModifierFns>,
             Option<&ConditionFns>,
@@ -455,6 +678,13 @@ fn update<S: ScheduleLabel>(
     mut conds_and_mods: Query<FilteredEntityMut>,
 ) {
     reader.clear_consumed::<S>();
+    reader.read_taps();
+    if *buffered {
+        reader.read_buffered();
+    }
+    if binding_changes.is_changed() {
+        depths.clear();
+    }
 
     for instance in &**instances {
         let Ok(mut context) = contexts.get_mut(instance.entity) else {
@@ -466,8 +696,19 @@ fn update<S: ScheduleLabel>(
         };
 
         let gamepad = context.get::<GamepadDevice>().copied().unwrap_or_default();
+        #[cfg(feature = "window")]
+        let window = context.get::<ContextWindow>().map(|w| **w);
         let context_active = instance.is_active(&context.as_readonly());
+        let blocking = instance.blocking(&context.as_readonly());
+        reader.set_context(Some(instance.entity));
+        #[cfg(feature = "input_focus")]
+        reader.set_focus_policy(context.get::<InputFocusPolicy>().copied());
         let Some(mut context_actions) = instance.actions_mut(&mut context) else {
+            if let Some(blocking) = blocking
+                && context_active
+            {
+                block_inputs::<S>(&mut reader, blocking, iter::empty());
+            }
             continue;
         };
 
@@ -478,19 +719,41 @@ fn update<S: ScheduleLabel>(
 
             let value = bindings
                 .iter_many(action_bindings.into_iter().flatten())
-                .map(|(_, b, ..)| b.mod_keys_count())
+                .map(|(_, b, ..)| b.mods_count())
                 .max()
                 .unwrap_or(0);
             Reverse(value)
         };
 
-        if !context_actions.is_sorted_by_key(mods_count) {
-            context_actions.sort_by_cached_key(mods_count);
+        let action_sources = |action: Entity| {
+            let Ok((.., action_bindings, _, _, _)) = actions.get(action) else {
+                return Vec::new();
+            };
+
+            bindings
+                .iter_many(action_bindings.into_iter().flatten())
+                .filter_map(|(_, b, ..)| match *b {
+                    Binding::Action(source) => Some(source),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let mut sort_key = |action: &Entity| {
+            (
+                action_depth(*action, &action_sources, &mut depths),
+                mods_count(action),
+            )
+        };
+
+        if !context_actions.is_sorted_by_key(&mut sort_key) {
+            context_actions.sort_by_cached_key(&mut sort_key);
         }
 
         trace!("updating `{}` on `{}`", instance.name, instance.entity);
 
-        reader.set_gamepad(gamepad);
+        #[cfg(feature = "window")]
+        reader.set_window(window);
 
         let mut actions_iter = actions.iter_many_mut(&*context_actions);
         while let Some((
@@ -536,22 +799,32 @@ fn update<S: ScheduleLabel>(
                 let dim = actions_data.get(action).map(|(v, ..)| v.dim()).unwrap();
                 let actions_data = actions_data.as_readonly();
                 let mut tracker = TriggerTracker::new(ActionValue::zero(dim));
+                reader.set_exact_mod_keys(action_settings.exact_mod_keys);
                 let mut bindings_iter =
                     bindings.iter_many_mut(action_bindings.into_iter().flatten());
                 while let Some((
                     binding_entity,
-                    &binding,
+                    binding,
+                    binding_gamepad,
                     mut first_activation,
                     modifiers,
                     conditions,
                 )) = bindings_iter.fetch_next()
                 {
-                    let new_value = reader.value(binding);
+                    let gamepad = binding_gamepad.copied().unwrap_or(gamepad);
+                    reader.set_gamepad(gamepad);
+                    let new_value = match *binding {
+                        Binding::Action(source) => actions_data
+                            .get(source)
+                            .map(|(&value, ..)| value)
+                            .unwrap_or_else(|_| false.into()),
+                        _ => reader.value(binding.clone()),
+                    };
                     if action_settings.require_reset && **first_activation {
                         // Ignore until we read zero for this mapping.
                         if new_value.as_bool() {
                             // Mark the binding input as consumed regardless of the end action state.
-                            reader.consume::<S>(binding);
+                            reader.consume::<S>(binding.clone());
                             continue;
                         } else {
                             **first_activation = false;
@@ -592,14 +865,14 @@ fn update<S: ScheduleLabel>(
                         Ordering::Equal => {
                             tracker.combine(current_tracker, action_settings.accumulation);
                             if action_settings.consume_input {
-                                consume_buffer.push(binding);
+                                consume_buffer.push((binding.clone(), gamepad));
                             }
                         }
                         Ordering::Greater => {
                             tracker.overwrite(current_tracker);
                             if action_settings.consume_input {
                                 consume_buffer.clear();
-                                consume_buffer.push(binding);
+                                consume_buffer.push((binding.clone(), gamepad));
                             }
                         }
                     }
@@ -618,7 +891,8 @@ fn update<S: ScheduleLabel>(
 
                 if action_settings.consume_input {
                     if new_state != TriggerState::None {
-                        for &binding in &consume_buffer {
+                        for (binding, gamepad) in consume_buffer.drain(..) {
+                            reader.set_gamepad(gamepad);
                             reader.consume::<S>(binding);
                         }
                     }
@@ -638,7 +912,67 @@ fn update<S: ScheduleLabel>(
             state.set_if_neq(new_state);
             value.set_if_neq(new_value);
         }
+
+        if let Some(blocking) = blocking
+            && context_active
+        {
+            let bound = actions
+                .iter_many(&*context_actions)
+                .flat_map(|(_, _, _, action_bindings, ..)| action_bindings.into_iter().flatten())
+                .filter_map(|binding| bindings.get(binding).ok())
+                .map(|(_, binding, binding_gamepad, ..)| {
+                    (binding.clone(), binding_gamepad.copied().unwrap_or(gamepad))
+                });
+            block_inputs::<S>(&mut reader, blocking, bound);
+        }
+    }
+}
+
+/// Makes inputs unavailable for the rest of the evaluation according to [`ContextBlocking`].
+///
+/// Bound inputs are passed along with their gamepads.
+fn block_inputs<S: ScheduleLabel>(
+    reader: &mut InputReader,
+    blocking: InputBlocking,
+    bound: impl Iterator<Item = (Binding, GamepadDevice)>,
+) {
+    trace!("blocking `{blocking:?}`");
+    match blocking {
+        InputBlocking::All => reader.block_devices::<S>(InputDevices::all()),
+        InputBlocking::Devices(devices) => reader.block_devices::<S>(devices),
+        InputBlocking::Bound => {
+            for (binding, gamepad) in bound {
+                reader.set_gamepad(gamepad);
+                reader.consume::<S>(binding);
+            }
+        }
+    }
+}
+
+/// Returns the length of the longest chain of [`Binding::Action`] references starting from the action.
+///
+/// Cycles are broken at the first revisited action. They are reported on binding insertion.
+///
+/// Results are cached in `depths`, which needs to be cleared when bindings change.
+fn action_depth(
+    action: Entity,
+    sources: &impl Fn(Entity) -> Vec<Entity>,
+    depths: &mut EntityHashMap<Option<usize>>,
+) -> usize {
+    if let Some(depth) = depths.get(&action) {
+        // `None` means that the action is still being visited.
+        return depth.unwrap_or_default();
     }
+
+    depths.insert(action, None);
+    let depth = sources(action)
+        .into_iter()
+        .map(|source| action_depth(source, sources, depths) + 1)
+        .max()
+        .unwrap_or_default();
+    depths.insert(action, Some(depth));
+
+    depth
 }
 
 pub type ActionsQuery<'w, 's> = Query<
@@ -681,6 +1015,10 @@ fn apply<S: ScheduleLabel>(
             let value = *action.get::<ActionValue>().unwrap();
             fns.store_value(&mut action, value);
 
+            // Re-read since dynamic actions convert the stored value to their dimension.
+
+            let value = *action.get::<ActionValue>().unwrap();
+
             let state = *action.get::<TriggerState>().unwrap();
             let events = *action.get::<ActionEvents>().unwrap();
             let time = *action.get::<ActionTime>().unwrap();
@@ -688,6 +1026,7 @@ fn apply<S: ScheduleLabel>(
                 &mut commands,
                 context.id(),
                 action.id(),
+                action.get::<Name>().unwrap(),
                 state,
                 events,
                 value,
@@ -706,6 +1045,8 @@ fn apply<S: ScheduleLabel>(
 /// For each action where [`ActionSettings::require_reset`] is set, it will require inputs for its bindings
 /// to be inactive before they will be visible to actions from other contexts.
 ///
+/// Changes trigger [`ContextActivated`] and [`ContextDeactivated`] on the context entity.
+///
 /// This is analogous to hiding an entity instead of despawning.
 /// Use this component when you want to toggle quickly, preserve bindings, or keep entity IDs.
 /// Use removal when the context is truly going away and you don't need it back soon.
@@ -722,6 +1063,8 @@ pub struct ContextActivity<C> {
     #[deref]
     active: bool,
     #[cfg_attr(feature = "reflect", reflect(ignore))]
+    reason: ActivityReason,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
     marker: PhantomData<C>,
 }
 
@@ -737,10 +1080,18 @@ impl<C> ContextActivity<C> {
     pub const fn new(active: bool) -> Self {
         Self {
             active,
+            reason: ActivityReason::Manual,
             marker: PhantomData,
         }
     }
 
+    /// Sets the reason reported by [`ContextActivated`] and [`ContextDeactivated`].
+    #[must_use]
+    pub(crate) const fn with_reason(mut self, reason: ActivityReason) -> Self {
+        self.reason = reason;
+        self
+    }
+
     /// Returns a new instance with the value inverted.
     #[must_use]
     pub const fn toggled(self) -> Self {
@@ -766,6 +1117,122 @@ impl<C> Clone for ContextActivity<C> {
 
 impl<C> Copy for ContextActivity<C> {}
 
+/// Why [`ContextActivity`] has changed.
+///
+/// Reported by [`ContextActivated`] and [`ContextDeactivated`].
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, Hash, PartialEq)
+)]
+pub enum ActivityReason {
+    /// [`ContextActivity`] was inserted manually.
+    #[default]
+    Manual,
+    /// The context was added to the entity.
+    Added,
+    /// [`ContextActivity`] was removed, usually together with the context or the entity.
+    Removed,
+    /// Synchronized with a state via `ActiveInStates`.
+    State,
+    /// Synchronized with a run condition via [`RunConditionContextAppExt::sync_context_to_condition`].
+    Condition,
+    /// Updated by [`ContextStack`].
+    Stack,
+}
+
+/// Triggered on the context entity when [`ContextActivity<C>`] changes to active.
+///
+/// Also triggered when an active context is added.
+#[derive(EntityEvent)]
+pub struct ContextActivated<C> {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Why the context was activated.
+    pub reason: ActivityReason,
+
+    marker: PhantomData<C>,
+}
+
+impl<C> ContextActivated<C> {
+    fn new(context: Entity, reason: ActivityReason) -> Self {
+        Self {
+            context,
+            reason,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Debug for ContextActivated<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextActivated")
+            .field("context", &self.context)
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
+
+impl<C> Clone for ContextActivated<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for ContextActivated<C> {}
+
+/// Triggered on the context entity when [`ContextActivity<C>`] changes to inactive.
+///
+/// Also triggered when an active context is removed, including despawns.
+#[derive(EntityEvent)]
+pub struct ContextDeactivated<C> {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Why the context was deactivated.
+    pub reason: ActivityReason,
+
+    marker: PhantomData<C>,
+}
+
+impl<C> ContextDeactivated<C> {
+    fn new(context: Entity, reason: ActivityReason) -> Self {
+        Self {
+            context,
+            reason,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Debug for ContextDeactivated<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextDeactivated")
+            .field("context", &self.context)
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
+
+impl<C> Clone for ContextDeactivated<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for ContextDeactivated<C> {}
+
+/// Activities of contexts that are being replaced or removed.
+///
+/// Stored between [`Replace`] and [`Insert`] or [`Remove`] observers of [`ContextActivity`]
+/// to detect changes, since the component is immutable.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(crate) struct ReplacedActivity(HashMap<(Entity, TypeId), bool>);
+
 #[cfg(feature = "reflect")]
 impl<C: 'static> TypePath for ContextActivity<C> {
     fn type_path() -> &'static str {
@@ -796,6 +1263,172 @@ impl<C: 'static> TypePath for ContextActivity<C> {
     }
 }
 
+/// Blocks inputs from reaching contexts evaluated after the input context `C` on the entity.
+///
+/// Unlike [`ActionSettings::consume_input`], which consumes only inputs of actions that were triggered,
+/// this works on the context level and doesn't depend on the action states. Useful for modal dialogs
+/// that should swallow all input.
+///
+/// Blocked inputs are treated as consumed for the rest of the evaluation while the context is active.
+/// Like with consumption, the order is determined by [`ContextPriority`] and schedules.
+/// Actions of the context itself are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Dialog,
+///     ContextPriority::<Dialog>::new(1),
+///     ContextBlocking::<Dialog>::ALL,
+///     // Actions...
+/// ));
+///
+/// #[derive(Component)]
+/// struct Dialog;
+/// ```
+#[derive(Component, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Default, type_path = false)
+)]
+pub struct ContextBlocking<C> {
+    #[deref]
+    blocking: InputBlocking,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    marker: PhantomData<C>,
+}
+
+impl<C> ContextBlocking<C> {
+    /// Blocks all inputs.
+    pub const ALL: Self = Self::new(InputBlocking::All);
+
+    /// Blocks inputs bound to the context actions.
+    pub const BOUND: Self = Self::new(InputBlocking::Bound);
+
+    /// Creates a new instance with the given value.
+    #[must_use]
+    pub const fn new(blocking: InputBlocking) -> Self {
+        Self {
+            blocking,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a new instance that blocks all inputs from the given devices.
+    #[must_use]
+    pub const fn devices(devices: InputDevices) -> Self {
+        Self::new(InputBlocking::Devices(devices))
+    }
+}
+
+impl<C> Default for ContextBlocking<C> {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl<C> Clone for ContextBlocking<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for ContextBlocking<C> {}
+
+#[cfg(feature = "reflect")]
+impl<C: 'static> TypePath for ContextBlocking<C> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                concat!(module_path!(), "::ContextBlocking<{}>"),
+                type_name::<C>()
+            )
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("ContextBlocking<{}>", type_name::<C>()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("ContextBlocking")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some(module_path!().split(':').next().unwrap())
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some(module_path!())
+    }
+}
+
+/// Inputs blocked by [`ContextBlocking`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputBlocking {
+    /// All inputs, including the ones that the context doesn't bind.
+    All,
+    /// Inputs bound to the context actions, regardless of their state.
+    Bound,
+    /// All inputs from the given devices.
+    Devices(InputDevices),
+}
+
+/// Input devices for [`InputBlocking::Devices`].
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputDevices(u8);
+
+bitflags! {
+    impl InputDevices: u8 {
+        /// Keys, including keyboard modifiers of other bindings.
+        const KEYBOARD = 1 << 0;
+        /// Mouse buttons, motion, wheel and cursor position.
+        const MOUSE = 1 << 1;
+        /// Buttons and axes of all gamepads, including gamepad modifiers of other bindings.
+        const GAMEPAD = 1 << 2;
+        /// All touches.
+        const TOUCH = 1 << 3;
+        /// All inputs from [`InputSource`]s.
+        const CUSTOM = 1 << 4;
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for InputDevices {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bitflags::serde::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for InputDevices {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bitflags::serde::deserialize(deserializer)
+    }
+}
+
 /// Determines the evaluation order of the input context `C` on the entity.
 ///
 /// Used to control how contexts are layered, as some [`Action<C>`]s may consume inputs.
@@ -808,6 +1441,9 @@ impl<C: 'static> TypePath for ContextActivity<C> {
 /// some actions take priority over others. This behavior can be customized per-action by setting
 /// [`ActionSettings::consume_input`].
 ///
+/// The priority can be changed at runtime by inserting a new value. The context will be
+/// re-sorted without affecting the state of its actions.
+///
 /// Marked as required for `C` on context registration.
 ///
 /// # Examples
@@ -900,6 +1536,9 @@ impl<C: 'static> TypePath for ContextPriority<C> {
 /// Associated gamepad for all input contexts on this entity.
 ///
 /// If not present, input will be read from all connected gamepads.
+///
+/// Can also be added to a [`Binding`] entity to override the gamepad of its context
+/// for this binding only. Useful for shared contexts, like a pause menu in local multiplayer.
 #[derive(Component, Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
@@ -939,6 +1578,24 @@ impl From<Option<Entity>> for GamepadDevice {
     }
 }
 
+/// Associated window for all input contexts on this entity.
+///
+/// Used by [`Binding::CursorPosition`] to read the cursor position.
+/// If not present, the [`PrimaryWindow`](bevy::window::PrimaryWindow) will be used.
+#[cfg(feature = "window")]
+#[derive(Component, Deref, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ContextWindow(pub Entity);
+
 /// Helper for tests to simplify [`InputTime`] and [`ActionsQuery`] creation.
 #[cfg(test)]
 pub(crate) fn init_world<'w, 's>() -> (World, SystemState<(ContextTime<'w>, ActionsQuery<'w, 's>)>)
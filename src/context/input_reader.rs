@@ -1,35 +1,101 @@
 use alloc::vec::Vec;
 use core::{any::TypeId, hash::Hash, iter, mem};
 
+#[cfg(feature = "input_focus")]
+use bevy::input_focus::{InputFocus, InputFocusVisible};
+#[cfg(feature = "window")]
+use bevy::window::PrimaryWindow;
 use bevy::{
-    ecs::{schedule::ScheduleLabel, system::SystemParam},
-    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
-    platform::collections::HashSet,
+    ecs::{
+        entity::EntityHashMap, message::MessageCursor, schedule::ScheduleLabel, system::SystemParam,
+    },
+    input::{
+        ButtonState,
+        gamepad::GamepadButtonStateChangedEvent,
+        keyboard::{Key, KeyboardInput},
+        mouse::{
+            AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButtonInput, MouseMotion,
+            MouseWheel,
+        },
+    },
+    platform::collections::{HashMap, HashSet},
     prelude::*,
     utils::TypeIdMap,
 };
 use log::{debug, trace};
 
-use crate::prelude::*;
+use crate::{
+    binding::{
+        input_source::SourceValues,
+        wheel_notch::{self, WheelNotches},
+    },
+    prelude::*,
+};
 
 pub(crate) fn update_pending(mut reader: InputReader) {
     reader.update_pending();
 }
 
+pub(crate) fn update_key_layout(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    keyboard_messages: Option<Res<Messages<KeyboardInput>>>,
+    mut keyboard_cursor: Local<MessageCursor<KeyboardInput>>,
+    mut layout: ResMut<KeyLayout>,
+) {
+    // Keep released keys until the next frame, so taps are still mapped.
+    layout.retain(|&key, _| keys.as_ref().is_some_and(|k| k.pressed(key)));
+
+    if let Some(messages) = &keyboard_messages {
+        for input in keyboard_cursor.read(messages) {
+            if input.state == ButtonState::Pressed {
+                layout.insert(input.key_code, input.logical_key.clone());
+            }
+        }
+    }
+}
+
 /// Input state for actions.
 ///
 /// Actions can read binding values and optionally consume them without affecting Bevy input resources.
 #[derive(SystemParam)]
 pub(crate) struct InputReader<'w, 's> {
     keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    logical_keys: Option<Res<'w, ButtonInput<Key>>>,
     mouse_buttons: Option<Res<'w, ButtonInput<MouseButton>>>,
+    keyboard_messages: Option<Res<'w, Messages<KeyboardInput>>>,
+    mouse_button_messages: Option<Res<'w, Messages<MouseButtonInput>>>,
+    gamepad_button_messages: Option<Res<'w, Messages<GamepadButtonStateChangedEvent>>>,
     mouse_motion: Option<Res<'w, AccumulatedMouseMotion>>,
     mouse_scroll: Option<Res<'w, AccumulatedMouseScroll>>,
+    mouse_motion_messages: Option<Res<'w, Messages<MouseMotion>>>,
+    mouse_wheel_messages: Option<Res<'w, Messages<MouseWheel>>>,
+    fixed_time: Option<Res<'w, Time<Fixed>>>,
+    wheel_notches: Res<'w, WheelNotches>,
+    wheel_notch_settings: Res<'w, WheelNotchSettings>,
+    touches: Option<Res<'w, Touches>>,
+    #[cfg(feature = "window")]
+    windows: Query<'w, 's, (Entity, &'static Window, Has<PrimaryWindow>)>,
     gamepads: Query<'w, 's, &'static Gamepad>,
+    source_values: Res<'w, SourceValues>,
+    #[cfg(feature = "input_focus")]
+    input_focus: Option<Res<'w, InputFocus>>,
+    #[cfg(feature = "input_focus")]
+    input_focus_visible: Option<Res<'w, InputFocusVisible>>,
     action_sources: Res<'w, ActionSources>,
+    context_sources: Query<'w, 's, &'static ActionSources>,
+    custom_sources: Res<'w, CustomSources>,
+    context_custom_sources: Query<'w, 's, &'static CustomSources>,
+    key_layout: Res<'w, KeyLayout>,
     consumed: ResMut<'w, ConsumedInputs>,
     pending: ResMut<'w, PendingBindings>,
+    taps: Local<'s, Taps>,
+    buffer: Local<'s, Option<InputBuffer>>,
     gamepad_device: Local<'s, GamepadDevice>,
+    exact_mod_keys: Local<'s, bool>,
+    #[cfg(feature = "window")]
+    window: Local<'s, Option<Entity>>,
+    context: Local<'s, Option<Entity>>,
+    suppressed_devices: Local<'s, InputDevices>,
     skip_ignore_check: Local<'s, bool>,
 }
 
@@ -39,13 +105,19 @@ impl InputReader<'_, '_> {
         // Updated before context-consumed inputs,
         // which may still reference inputs added to the pending.
         *self.skip_ignore_check = true;
+        *self.exact_mod_keys = false;
 
         // Temporary take the original value to avoid issues with the borrow checker.
         let mut pending = mem::take(&mut *self.pending);
         pending.ignored.clear();
-        pending.bindings.retain(|&binding| {
-            if self.value(binding).as_bool() {
-                pending.ignored.add(binding, *self.gamepad_device);
+        pending.context_ignored.clear();
+        pending.bindings.retain(|(binding, context)| {
+            if self.value(binding.clone()).as_bool() {
+                let ignored = match context {
+                    Some(context) => pending.context_ignored.entry(*context).or_default(),
+                    None => &mut pending.ignored,
+                };
+                ignored.add(binding.clone(), *self.gamepad_device, &self.key_layout);
                 true
             } else {
                 trace!("'{binding}' reset and no longer ignored");
@@ -57,6 +129,99 @@ impl InputReader<'_, '_> {
         *self.skip_ignore_check = false
     }
 
+    /// Reads button presses since the last call.
+    ///
+    /// Buttons pressed and released in between will be treated as pressed by [`Self::value`]
+    /// until the next call, so they're evaluated at least once.
+    pub(crate) fn read_taps(&mut self) {
+        let Taps {
+            keyboard_cursor,
+            mouse_button_cursor,
+            gamepad_button_cursor,
+            keys,
+            logical_keys,
+            mouse_buttons,
+            gamepad_buttons,
+        } = &mut *self.taps;
+
+        keys.clear();
+        logical_keys.clear();
+        if let Some(messages) = &self.keyboard_messages {
+            for input in keyboard_cursor.read(messages) {
+                if input.state == ButtonState::Pressed && !input.repeat {
+                    keys.insert(input.key_code);
+                    logical_keys.insert(input.logical_key.clone());
+                }
+            }
+        }
+
+        mouse_buttons.clear();
+        if let Some(messages) = &self.mouse_button_messages {
+            mouse_buttons.extend(
+                mouse_button_cursor
+                    .read(messages)
+                    .filter(|input| input.state == ButtonState::Pressed)
+                    .map(|input| input.button),
+            );
+        }
+
+        gamepad_buttons.clear();
+        if let Some(messages) = &self.gamepad_button_messages {
+            gamepad_buttons.extend(
+                gamepad_button_cursor
+                    .read(messages)
+                    .filter(|input| input.state == ButtonState::Pressed)
+                    .map(|input| (input.entity, input.button)),
+            );
+        }
+    }
+
+    /// Reads mouse deltas since the last call and takes a share for the current schedule run.
+    ///
+    /// Once called, [`Self::value`] will read deltas and wheel notches from the buffer instead of the
+    /// per-frame resources. The accumulated deltas are split evenly across the remaining
+    /// [`FixedMain`] runs in this frame and carried over to the next frame if the schedule doesn't run.
+    pub(crate) fn read_buffered(&mut self) {
+        let buffer = self.buffer.get_or_insert_default();
+
+        if let Some(messages) = &self.mouse_motion_messages {
+            for motion in buffer.mouse_motion_cursor.read(messages) {
+                buffer.pending_motion += motion.delta;
+            }
+        }
+
+        if let Some(messages) = &self.mouse_wheel_messages {
+            for wheel in buffer.mouse_wheel_cursor.read(messages) {
+                let delta = Vec2::new(wheel.x, wheel.y);
+                buffer.pending_scroll += delta;
+                buffer.pending_lines +=
+                    wheel_notch::lines(&self.wheel_notch_settings, wheel.unit, delta);
+            }
+        }
+
+        let remaining_runs = self
+            .fixed_time
+            .as_ref()
+            .map(|time| time.overstep().as_nanos() / time.timestep().as_nanos().max(1))
+            .unwrap_or_default();
+        let share = 1.0 / (remaining_runs + 1) as f32;
+
+        buffer.mouse_motion = buffer.pending_motion * share;
+        buffer.pending_motion -= buffer.mouse_motion;
+
+        buffer.mouse_scroll = buffer.pending_scroll * share;
+        buffer.pending_scroll -= buffer.mouse_scroll;
+
+        let lines = buffer.pending_lines * share;
+        buffer.pending_lines -= lines;
+        buffer.wheel_notches.advance(lines);
+
+        trace!(
+            "taking {share} of buffered input with {remaining_runs} remaining runs: motion `{}`, scroll `{}`",
+            buffer.mouse_motion, buffer.mouse_scroll
+        );
+    }
+
     /// Clears all consumed values from the given schedule.
     pub(crate) fn clear_consumed<S: ScheduleLabel>(&mut self) {
         self.consumed.entry(TypeId::of::<S>()).or_default().clear();
@@ -67,6 +232,54 @@ impl InputReader<'_, '_> {
         *self.gamepad_device = gamepad.into();
     }
 
+    /// Configures whether [`Self::value`] should reject bindings when extra modifiers are pressed.
+    ///
+    /// See [`ActionSettings::exact_mod_keys`].
+    pub(crate) fn set_exact_mod_keys(&mut self, exact: bool) {
+        *self.exact_mod_keys = exact;
+    }
+
+    /// Assigns a window from which [`Self::value`] should read the cursor position.
+    ///
+    /// If [`None`], the primary window will be used.
+    #[cfg(feature = "window")]
+    pub(crate) fn set_window(&mut self, window: Option<Entity>) {
+        *self.window = window;
+    }
+
+    /// Assigns the context entity whose [`ActionSources`] and [`CustomSources`] components
+    /// [`Self::value`] should use instead of the resources.
+    ///
+    /// If [`None`] or the entity doesn't have a component, the corresponding resource will be used.
+    pub(crate) fn set_context(&mut self, context: Option<Entity>) {
+        *self.context = context;
+    }
+
+    /// Assigns a policy for [`Self::value`] to suppress devices while the UI holds the input focus.
+    #[cfg(feature = "input_focus")]
+    pub(crate) fn set_focus_policy(&mut self, policy: Option<InputFocusPolicy>) {
+        *self.suppressed_devices = policy
+            .map(|policy| {
+                policy.suppressed(
+                    self.input_focus.as_deref(),
+                    self.input_focus_visible.as_deref(),
+                )
+            })
+            .unwrap_or_default();
+    }
+
+    fn sources(&self) -> &ActionSources {
+        self.context
+            .and_then(|context| self.context_sources.get(context).ok())
+            .unwrap_or(&self.action_sources)
+    }
+
+    fn custom_sources(&self) -> &CustomSources {
+        self.context
+            .and_then(|context| self.context_custom_sources.get(context).ok())
+            .unwrap_or(&self.custom_sources)
+    }
+
     /// Returns the [`ActionValue`] for the given [`Binding`].
     ///
     /// See also [`Self::consume`] and [`Self::set_gamepad`].
@@ -74,32 +287,51 @@ impl InputReader<'_, '_> {
         let binding = binding.into();
         match binding {
             Binding::Keyboard { key, mod_keys } => {
-                let pressed = self.action_sources.keyboard
-                    && self.keys.as_ref().is_some_and(|k| k.pressed(key))
+                // Include the key itself, in case it's a modifier,
+                // so it won't be treated as extra.
+                let pressed = self.sources().keyboard
+                    && self.key_pressed(key)
+                    && self.mod_keys_pressed(mod_keys | key.into())
+                    && !self.ignored(binding);
+
+                pressed.into()
+            }
+            Binding::LogicalKey { ref key, mod_keys } => {
+                let pressed = self.sources().keyboard
+                    && (self
+                        .logical_keys
+                        .as_ref()
+                        .is_some_and(|k| k.pressed(key.clone()))
+                        || self.taps.logical_keys.contains(key))
                     && self.mod_keys_pressed(mod_keys)
                     && !self.ignored(binding);
 
                 pressed.into()
             }
             Binding::MouseButton { button, mod_keys } => {
-                let pressed = self.action_sources.mouse_buttons
-                    && self
+                let pressed = self.sources().mouse_buttons
+                    && (self
                         .mouse_buttons
                         .as_ref()
                         .is_some_and(|b| b.pressed(button))
+                        || self.taps.mouse_buttons.contains(&button))
                     && self.mod_keys_pressed(mod_keys)
                     && !self.ignored(binding);
 
                 pressed.into()
             }
             Binding::MouseMotion { mod_keys } => {
-                if !self.action_sources.mouse_motion
+                if !self.sources().mouse_motion
                     || !self.mod_keys_pressed(mod_keys)
                     || self.ignored(binding)
                 {
                     return Vec2::ZERO.into();
                 }
 
+                if let Some(buffer) = &*self.buffer {
+                    return buffer.mouse_motion.into();
+                }
+
                 self.mouse_motion
                     .as_ref()
                     .map(|m| m.delta)
@@ -107,21 +339,51 @@ impl InputReader<'_, '_> {
                     .into()
             }
             Binding::MouseWheel { mod_keys } => {
-                if !self.action_sources.mouse_wheel
+                if !self.sources().mouse_wheel
                     || !self.mod_keys_pressed(mod_keys)
                     || self.ignored(binding)
                 {
                     return Vec2::ZERO.into();
                 }
 
+                if let Some(buffer) = &*self.buffer {
+                    return buffer.mouse_scroll.into();
+                }
+
                 self.mouse_scroll
                     .as_ref()
                     .map(|s| s.delta)
                     .unwrap_or_default()
                     .into()
             }
-            Binding::GamepadButton(button) => {
-                if !self.action_sources.gamepad_button || self.ignored(binding) {
+            Binding::MouseWheelNotch {
+                direction,
+                mod_keys,
+            } => {
+                let pressed = self.sources().mouse_wheel
+                    && self
+                        .buffer
+                        .as_ref()
+                        .map(|b| &b.wheel_notches)
+                        .unwrap_or(&self.wheel_notches)
+                        .pressed(direction)
+                    && self.mod_keys_pressed(mod_keys)
+                    && !self.ignored(binding);
+
+                pressed.into()
+            }
+            Binding::CursorPosition { normalized } => {
+                if !self.sources().cursor_position || self.ignored(binding) {
+                    return Vec2::ZERO.into();
+                }
+
+                self.cursor_position(normalized).into()
+            }
+            Binding::GamepadButton {
+                button,
+                mod_buttons,
+            } => {
+                if !self.sources().gamepad_button || self.ignored(binding) {
                     return 0.0.into();
                 }
 
@@ -129,66 +391,158 @@ impl InputReader<'_, '_> {
                     GamepadDevice::Any => self
                         .gamepads
                         .iter()
+                        .filter(|gamepad| self.mod_buttons_pressed(gamepad, mod_buttons))
                         .filter_map(|gamepad| gamepad.get(button))
                         .find(|&value| value != 0.0),
                     GamepadDevice::Single(entity) => self
                         .gamepads
                         .get(entity)
                         .ok()
+                        .filter(|gamepad| self.mod_buttons_pressed(gamepad, mod_buttons))
                         .and_then(|gamepad| gamepad.get(button)),
                     GamepadDevice::None => return 0.0.into(),
                 };
 
-                value.unwrap_or_default().into()
+                match value {
+                    Some(value) if value != 0.0 => value.into(),
+                    _ if self.gamepad_button_tapped(button, mod_buttons) => 1.0.into(),
+                    _ => 0.0.into(),
+                }
             }
-            Binding::GamepadAxis(axis) => {
-                if !self.action_sources.gamepad_axis || self.ignored(binding) {
+            Binding::GamepadAxis { axis, mod_buttons } => {
+                if !self.sources().gamepad_axis || self.ignored(binding) {
                     return 0.0.into();
                 }
 
+                self.gamepad_axis_value(axis, mod_buttons).into()
+            }
+            Binding::GamepadAxisDirection {
+                axis,
+                positive,
+                threshold,
+            } => {
+                if !self.sources().gamepad_axis || self.ignored(binding) {
+                    return false.into();
+                }
+
+                let value = self.gamepad_axis_value(axis, ModButtons::empty());
+                let value = if positive { value } else { -value };
+                (value >= threshold).into()
+            }
+            Binding::GamepadStick(stick) => {
+                if !self.sources().gamepad_axis || self.ignored(binding) {
+                    return Vec2::ZERO.into();
+                }
+
+                let [x, y] = stick.axes();
                 let value = match *self.gamepad_device {
                     GamepadDevice::Any => self
                         .gamepads
                         .iter()
-                        .filter_map(|gamepad| gamepad.get_unclamped(axis))
-                        .reduce(|acc, v| acc + v),
+                        .map(|gamepad| {
+                            Vec2::new(
+                                gamepad.get_unclamped(x).unwrap_or_default(),
+                                gamepad.get_unclamped(y).unwrap_or_default(),
+                            )
+                        })
+                        .sum(),
                     GamepadDevice::Single(entity) => self
                         .gamepads
                         .get(entity)
-                        .ok()
-                        .and_then(|gamepad| gamepad.get(axis)),
-                    GamepadDevice::None => return 0.0.into(),
+                        .map(|gamepad| {
+                            Vec2::new(
+                                gamepad.get(x).unwrap_or_default(),
+                                gamepad.get(y).unwrap_or_default(),
+                            )
+                        })
+                        .unwrap_or_default(),
+                    GamepadDevice::None => Vec2::ZERO,
                 };
 
-                let value = value.unwrap_or_default();
                 value.into()
             }
+            Binding::AnyTouch => {
+                let touched = self.sources().touch
+                    && !self.ignored(binding)
+                    && self
+                        .touches
+                        .iter()
+                        .flat_map(|t| t.iter())
+                        .any(|t| !self.touch_ignored(t.position()));
+
+                touched.into()
+            }
+            Binding::TouchArea(area) => {
+                let touched = self.sources().touch
+                    && !self.ignored(binding)
+                    && self
+                        .touches
+                        .iter()
+                        .flat_map(|t| t.iter())
+                        .filter(|t| area.contains(t.position()))
+                        .any(|t| !self.touch_ignored(t.position()));
+
+                touched.into()
+            }
+            Binding::TouchDrag => {
+                if !self.sources().touch || self.ignored(binding) {
+                    return Vec2::ZERO.into();
+                }
+
+                let mut count = 0;
+                let mut delta = Vec2::ZERO;
+                for touch in self.touches.iter().flat_map(|t| t.iter()) {
+                    delta += touch.delta();
+                    count += 1;
+                }
+
+                if count == 0 {
+                    return Vec2::ZERO.into();
+                }
+
+                (delta / count as f32).into()
+            }
+            Binding::Custom(ref input) => {
+                let Some(&value) = self.source_values.get(input) else {
+                    return false.into();
+                };
+
+                if !self.custom_sources().enabled_by_name(&input.source) || self.ignored(binding) {
+                    return ActionValue::zero(value.dim());
+                }
+
+                value
+            }
+            // Action values are read directly by the context update.
+            Binding::Action(_) => false.into(),
             Binding::AnyKey => {
                 if self.ignored(Binding::AnyKey) {
                     return false.into();
                 }
 
-                if self.action_sources.keyboard
+                if self.sources().keyboard
                     && self
                         .keys
                         .iter()
                         .flat_map(|k| k.get_pressed())
+                        .chain(&self.taps.keys)
                         .any(|&k| !self.ignored(k))
                 {
                     return true.into();
                 }
 
-                if self.action_sources.mouse_buttons
+                if self.sources().mouse_buttons
                     && self
                         .mouse_buttons
                         .iter()
                         .flat_map(|b| b.get_pressed())
+                        .chain(&self.taps.mouse_buttons)
                         .any(|&b| !self.ignored(b))
                 {
                     return true.into();
                 }
 
-                if self.action_sources.gamepad_button {
+                if self.sources().gamepad_button {
                     match *self.gamepad_device {
                         GamepadDevice::Single(entity) => {
                             if let Ok(gamepad) = self.gamepads.get(entity)
@@ -206,6 +560,13 @@ impl InputReader<'_, '_> {
                         }
                         GamepadDevice::None => (),
                     };
+
+                    if self
+                        .tapped_gamepad_buttons()
+                        .any(|(_, button)| !self.ignored(button))
+                    {
+                        return true.into();
+                    }
                 }
 
                 false.into()
@@ -214,70 +575,263 @@ impl InputReader<'_, '_> {
         }
     }
 
+    /// Returns the cursor position for [`Binding::CursorPosition`] in the context window.
+    #[cfg(feature = "window")]
+    fn cursor_position(&self, normalized: bool) -> Vec2 {
+        let window = match *self.window {
+            Some(entity) => self.windows.get(entity).ok(),
+            None => self.windows.iter().find(|&(.., primary)| primary),
+        };
+        let Some((_, window, _)) = window else {
+            return Vec2::ZERO;
+        };
+        let Some(position) = window.cursor_position() else {
+            return Vec2::ZERO;
+        };
+
+        if normalized {
+            position / window.size()
+        } else {
+            position
+        }
+    }
+
+    /// Windows are unavailable without the `window` feature, so the position is always zero.
+    #[cfg(not(feature = "window"))]
+    fn cursor_position(&self, _normalized: bool) -> Vec2 {
+        Vec2::ZERO
+    }
+
     fn mod_keys_pressed(&self, mod_keys: ModKeys) -> bool {
-        if !mod_keys.is_empty() && !self.action_sources.keyboard {
+        if !mod_keys.is_empty() && !self.sources().keyboard {
             return false;
         }
 
-        for keys in mod_keys.iter_keys() {
-            if self.keys.as_ref().is_none_or(|k| !k.any_pressed(keys)) {
-                return false;
-            }
+        if !mod_keys
+            .iter_keys()
+            .all(|keys| keys.iter().any(|&key| self.key_pressed(key)))
+        {
+            return false;
+        }
+
+        if *self.exact_mod_keys
+            && ModKeys::all()
+                .difference(mod_keys)
+                .iter_keys()
+                .any(|keys| keys.iter().any(|&key| self.key_pressed(key)))
+        {
+            return false;
         }
 
         true
     }
 
+    /// Returns `true` if the key is pressed or was tapped since the last [`Self::read_taps`].
+    fn key_pressed(&self, key: KeyCode) -> bool {
+        self.keys.as_ref().is_some_and(|k| k.pressed(key)) || self.taps.keys.contains(&key)
+    }
+
+    /// Returns buttons pressed on the assigned gamepad since the last [`Self::read_taps`].
+    fn tapped_gamepad_buttons(&self) -> impl Iterator<Item = (Entity, GamepadButton)> {
+        self.taps
+            .gamepad_buttons
+            .iter()
+            .copied()
+            .filter(|&(entity, _)| match *self.gamepad_device {
+                GamepadDevice::Any => true,
+                GamepadDevice::Single(gamepad) => gamepad == entity,
+                GamepadDevice::None => false,
+            })
+    }
+
+    fn gamepad_button_tapped(&self, button: GamepadButton, mod_buttons: ModButtons) -> bool {
+        self.tapped_gamepad_buttons()
+            .filter(|&(_, tapped)| tapped == button)
+            .filter_map(|(entity, _)| self.gamepads.get(entity).ok())
+            .any(|gamepad| self.mod_buttons_pressed(gamepad, mod_buttons))
+    }
+
+    /// Returns the value of the axis from the assigned gamepad.
+    ///
+    /// Values from all gamepads are summed for [`GamepadDevice::Any`].
+    fn gamepad_axis_value(&self, axis: GamepadAxis, mod_buttons: ModButtons) -> f32 {
+        let value = match *self.gamepad_device {
+            GamepadDevice::Any => self
+                .gamepads
+                .iter()
+                .filter(|gamepad| self.mod_buttons_pressed(gamepad, mod_buttons))
+                .filter_map(|gamepad| gamepad.get_unclamped(axis))
+                .reduce(|acc, v| acc + v),
+            GamepadDevice::Single(entity) => self
+                .gamepads
+                .get(entity)
+                .ok()
+                .filter(|gamepad| self.mod_buttons_pressed(gamepad, mod_buttons))
+                .and_then(|gamepad| gamepad.get(axis)),
+            GamepadDevice::None => None,
+        };
+
+        value.unwrap_or_default()
+    }
+
+    fn mod_buttons_pressed(&self, gamepad: &Gamepad, mod_buttons: ModButtons) -> bool {
+        if !mod_buttons.is_empty() && !self.sources().gamepad_button {
+            return false;
+        }
+
+        mod_buttons
+            .iter_buttons()
+            .all(|button| gamepad.pressed(button))
+    }
+
+    /// Returns `true` if a touch at the given position was consumed by a touch area.
+    fn touch_ignored(&self, position: Vec2) -> bool {
+        if *self.skip_ignore_check {
+            return false;
+        }
+
+        if self.suppressed_devices.contains(InputDevices::TOUCH) {
+            return true;
+        }
+
+        self.ignored_inputs().any(|i| {
+            i.devices.contains(InputDevices::TOUCH)
+                || i.touch_areas.iter().any(|area| area.contains(position))
+        })
+    }
+
     fn ignored(&self, binding: impl Into<Binding>) -> bool {
         if *self.skip_ignore_check {
             return false;
         }
 
-        let keys_ignored =
-            self.pending.ignored.any_key || self.consumed.values().any(|ignored| ignored.any_key);
-        let mut iter = iter::once(&self.pending.ignored).chain(self.consumed.values());
-        match binding.into() {
+        let binding = binding.into();
+        let mut iter = self.ignored_inputs();
+        let devices = binding_devices(&binding);
+        if self.suppressed_devices.intersects(devices) {
+            return true;
+        }
+        if iter.clone().any(|i| i.devices.intersects(devices)) {
+            return true;
+        }
+
+        let keys_ignored = iter.clone().any(|ignored| ignored.any_key);
+        // Consumed modifiers block only the sides that are currently held.
+        let held_mod_keys = ModKeys::pressed_sides(|key| self.key_pressed(key));
+        match binding {
             Binding::Keyboard { key, mod_keys } => {
-                iter.any(|i| i.keys.contains(&key) || i.mod_keys.intersects(mod_keys))
-                    || keys_ignored
-            }
-            Binding::MouseButton { button, mod_keys } => {
-                iter.any(|i| i.mouse_buttons.contains(&button) || i.mod_keys.intersects(mod_keys))
-                    || keys_ignored
+                iter.any(|i| {
+                    i.keys.contains(&key) || i.mod_keys.intersects(mod_keys & held_mod_keys)
+                }) || keys_ignored
             }
-            Binding::MouseMotion { mod_keys } => {
-                iter.any(|inputs| inputs.mouse_motion || inputs.mod_keys.intersects(mod_keys))
+            Binding::LogicalKey { key, mod_keys } => {
+                iter.any(|i| {
+                    i.logical_keys.contains(&key) || i.mod_keys.intersects(mod_keys & held_mod_keys)
+                }) || keys_ignored
             }
-            Binding::MouseWheel { mod_keys } => {
-                iter.any(|inputs| inputs.mouse_wheel || inputs.mod_keys.intersects(mod_keys))
+            Binding::MouseButton { button, mod_keys } => {
+                iter.any(|i| {
+                    i.mouse_buttons.contains(&button)
+                        || i.mod_keys.intersects(mod_keys & held_mod_keys)
+                }) || keys_ignored
             }
-            Binding::GamepadButton(button) => {
+            Binding::MouseMotion { mod_keys } => iter.any(|inputs| {
+                inputs.mouse_motion || inputs.mod_keys.intersects(mod_keys & held_mod_keys)
+            }),
+            Binding::MouseWheel { mod_keys } => iter.any(|inputs| {
+                inputs.mouse_wheel
+                    || !inputs.wheel_notches.is_empty()
+                    || inputs.mod_keys.intersects(mod_keys & held_mod_keys)
+            }),
+            Binding::MouseWheelNotch {
+                direction,
+                mod_keys,
+            } => iter.any(|inputs| {
+                inputs.mouse_wheel
+                    || inputs.wheel_notches.contains(&direction)
+                    || inputs.mod_keys.intersects(mod_keys & held_mod_keys)
+            }),
+            Binding::CursorPosition { .. } => iter.any(|inputs| inputs.cursor_position),
+            Binding::GamepadButton {
+                button,
+                mod_buttons,
+            } => {
                 let input = GamepadInput {
                     gamepad: *self.gamepad_device,
                     input: button,
                 };
-                iter.any(|inputs| inputs.gamepad_buttons.contains(&input)) || keys_ignored
+                iter.any(|inputs| {
+                    inputs.gamepad_buttons.contains(&input)
+                        || inputs.mod_buttons_ignored(*self.gamepad_device, mod_buttons)
+                }) || keys_ignored
+            }
+            Binding::GamepadAxis { axis, mod_buttons } => {
+                let gamepad = *self.gamepad_device;
+                iter.any(|inputs| {
+                    inputs.gamepad_axis_ignored(gamepad, axis)
+                        || inputs.mod_buttons_ignored(gamepad, mod_buttons)
+                })
             }
-            Binding::GamepadAxis(axis) => {
+            Binding::GamepadAxisDirection { axis, positive, .. } => {
                 let input = GamepadInput {
                     gamepad: *self.gamepad_device,
                     input: axis,
                 };
-                iter.any(|inputs| inputs.gamepad_axes.contains(&input))
+                let direction = GamepadInput {
+                    gamepad: *self.gamepad_device,
+                    input: (axis, positive),
+                };
+                iter.any(|inputs| {
+                    inputs.gamepad_axes.contains(&input)
+                        || inputs.gamepad_axis_directions.contains(&direction)
+                })
             }
+            Binding::GamepadStick(stick) => iter.any(|inputs| {
+                stick
+                    .axes()
+                    .into_iter()
+                    .any(|axis| inputs.gamepad_axis_ignored(*self.gamepad_device, axis))
+            }),
+            Binding::AnyTouch | Binding::TouchArea(_) => iter.any(|inputs| inputs.any_touch),
+            Binding::TouchDrag => iter.any(|inputs| inputs.touch_drag),
+            Binding::Custom(input) => iter.any(|inputs| inputs.custom.contains(&input)),
+            Binding::Action(_) => false,
             Binding::AnyKey => keys_ignored,
             Binding::None => false,
         }
     }
 
-    /// Consumes the binding input, making it unavailable for [`Self::value`].
+    /// Returns pending inputs that apply to the assigned context and inputs consumed in all schedules.
+    fn ignored_inputs(&self) -> impl Iterator<Item = &IgnoredInputs> + Clone {
+        let context_pending = self
+            .context
+            .and_then(|context| self.pending.context_ignored.get(&context));
+
+        iter::once(&self.pending.ignored)
+            .chain(context_pending)
+            .chain(self.consumed.values())
+    }
+
+    /// Makes all inputs from the devices unavailable for [`Self::value`].
     ///
     /// Clears for this schedule with [`Self::clear_consumed`].
-    pub(crate) fn consume<S: ScheduleLabel>(&mut self, binding: impl Into<Binding>) {
+    pub(crate) fn block_devices<S: ScheduleLabel>(&mut self, devices: InputDevices) {
         self.consumed
             .entry(TypeId::of::<S>())
             .or_default()
-            .add(binding.into(), *self.gamepad_device);
+            .devices
+            .insert(devices);
+    }
+
+    /// Consumes the binding input, making it unavailable for [`Self::value`].
+    ///
+    /// Clears for this schedule with [`Self::clear_consumed`].
+    pub(crate) fn consume<S: ScheduleLabel>(&mut self, binding: impl Into<Binding>) {
+        self.consumed.entry(TypeId::of::<S>()).or_default().add(
+            binding.into(),
+            *self.gamepad_device,
+            &self.key_layout,
+        );
     }
 }
 
@@ -285,9 +839,15 @@ impl InputReader<'_, '_> {
 ///
 /// Defaults to `true` for all values.
 ///
+/// Can be inserted as a resource to apply globally, or as a component on a context entity
+/// to override the resource for all input contexts on this entity. For example, in split-screen
+/// each player can disable mouse buttons only for their own context while hovering their UI.
+///
 /// Could be used to prevent actions from being triggered
 /// while interacting with the UI.
 ///
+/// Inputs from custom sources are configured separately using [`CustomSources`].
+///
 /// # Examples
 ///
 /// Disables mouse buttons for actions when the cursor hovers a node with
@@ -310,15 +870,21 @@ impl InputReader<'_, '_> {
 ///     action_sources.mouse_wheel = mouse_unused;
 /// }
 /// ```
-#[derive(Resource)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default, Resource))]
+#[derive(Resource, Component, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Default, Resource)
+)]
 pub struct ActionSources {
     pub keyboard: bool,
     pub mouse_buttons: bool,
     pub mouse_motion: bool,
     pub mouse_wheel: bool,
+    pub cursor_position: bool,
     pub gamepad_button: bool,
     pub gamepad_axis: bool,
+    pub touch: bool,
 }
 
 impl Default for ActionSources {
@@ -328,8 +894,10 @@ impl Default for ActionSources {
             mouse_buttons: true,
             mouse_motion: true,
             mouse_wheel: true,
+            cursor_position: true,
             gamepad_button: true,
             gamepad_axis: true,
+            touch: true,
         }
     }
 }
@@ -351,36 +919,84 @@ pub(crate) struct ConsumedInputs(TypeIdMap<IgnoredInputs>);
 /// Once inactive, they will be automatically removed and no longer ignored.
 #[derive(Resource, Default)]
 pub(crate) struct PendingBindings {
-    bindings: Vec<Binding>,
+    /// Bindings with the context entity to which they apply, or [`None`] if they apply to all contexts.
+    bindings: Vec<(Binding, Option<Entity>)>,
 
-    /// Computed from [`Self::bindings`].
+    /// Computed from [`Self::bindings`] without a context entity.
     ignored: IgnoredInputs,
+
+    /// Computed from [`Self::bindings`] for each context entity.
+    context_ignored: EntityHashMap<IgnoredInputs>,
 }
 
 impl PendingBindings {
+    /// Ignores inputs of the bindings for all contexts until reset.
     pub(crate) fn extend(&mut self, iter: impl Iterator<Item = Binding>) {
-        self.bindings
-            .extend(iter.inspect(|binding| debug!("ignoring '{binding}' until reset")));
+        self.bindings.extend(
+            iter.inspect(|binding| debug!("ignoring '{binding}' until reset"))
+                .map(|binding| (binding, None)),
+        );
+    }
+
+    /// Like [`Self::extend`], but ignores inputs only for contexts on the entity.
+    #[cfg(feature = "window")]
+    pub(crate) fn extend_for(&mut self, context: Entity, iter: impl Iterator<Item = Binding>) {
+        self.bindings.extend(
+            iter.inspect(|binding| debug!("ignoring '{binding}' for `{context}` until reset"))
+                .map(|binding| (binding, Some(context))),
+        );
     }
 }
 
+/// Logical keys produced by the currently pressed physical keys.
+///
+/// Allows [`Binding::Keyboard`] and [`Binding::LogicalKey`] to consume each other
+/// when they refer to the same key.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(crate) struct KeyLayout(HashMap<KeyCode, Key>);
+
 #[derive(Default)]
 pub(crate) struct IgnoredInputs {
     keys: HashSet<KeyCode>,
+    logical_keys: HashSet<Key>,
     mod_keys: ModKeys,
     mouse_buttons: HashSet<MouseButton>,
     mouse_motion: bool,
     mouse_wheel: bool,
+    wheel_notches: HashSet<WheelDirection>,
+    cursor_position: bool,
     gamepad_buttons: HashSet<GamepadInput<GamepadButton>>,
     gamepad_axes: HashSet<GamepadInput<GamepadAxis>>,
+    /// Axes with direction, where `true` means positive.
+    gamepad_axis_directions: HashSet<GamepadInput<(GamepadAxis, bool)>>,
+    mod_buttons: HashMap<GamepadDevice, ModButtons>,
+    touch_areas: Vec<Rect>,
+    any_touch: bool,
+    touch_drag: bool,
+    custom: HashSet<CustomInput>,
     any_key: bool,
+    /// Devices from which all inputs are ignored.
+    devices: InputDevices,
 }
 
 impl IgnoredInputs {
-    fn add(&mut self, binding: Binding, gamepad: GamepadDevice) {
+    fn add(&mut self, binding: Binding, gamepad: GamepadDevice, layout: &KeyLayout) {
         match binding {
             Binding::Keyboard { key, mod_keys } => {
                 self.keys.insert(key);
+                if let Some(logical_key) = layout.get(&key) {
+                    self.logical_keys.insert(logical_key.clone());
+                }
+                self.mod_keys.insert(mod_keys);
+            }
+            Binding::LogicalKey { key, mod_keys } => {
+                self.keys.extend(
+                    layout
+                        .iter()
+                        .filter(|&(_, logical_key)| *logical_key == key)
+                        .map(|(&key, _)| key),
+                );
+                self.logical_keys.insert(key);
                 self.mod_keys.insert(mod_keys);
             }
             Binding::MouseButton { button, mod_keys } => {
@@ -395,22 +1011,60 @@ impl IgnoredInputs {
                 self.mouse_wheel = true;
                 self.mod_keys.insert(mod_keys);
             }
-            Binding::GamepadButton(button) => {
+            Binding::MouseWheelNotch {
+                direction,
+                mod_keys,
+            } => {
+                self.wheel_notches.insert(direction);
+                self.mod_keys.insert(mod_keys);
+            }
+            Binding::CursorPosition { .. } => self.cursor_position = true,
+            Binding::GamepadButton {
+                button,
+                mod_buttons,
+            } => {
                 let input = GamepadInput {
                     gamepad,
                     input: button,
                 };
 
                 self.gamepad_buttons.insert(input);
+                self.add_mod_buttons(gamepad, mod_buttons);
             }
-            Binding::GamepadAxis(axis) => {
+            Binding::GamepadAxis { axis, mod_buttons } => {
                 let input = GamepadInput {
                     gamepad,
                     input: axis,
                 };
 
                 self.gamepad_axes.insert(input);
+                self.add_mod_buttons(gamepad, mod_buttons);
+            }
+            Binding::GamepadAxisDirection { axis, positive, .. } => {
+                let input = GamepadInput {
+                    gamepad,
+                    input: (axis, positive),
+                };
+
+                self.gamepad_axis_directions.insert(input);
+            }
+            Binding::GamepadStick(stick) => {
+                for axis in stick.axes() {
+                    let input = GamepadInput {
+                        gamepad,
+                        input: axis,
+                    };
+
+                    self.gamepad_axes.insert(input);
+                }
             }
+            Binding::AnyTouch => self.any_touch = true,
+            Binding::TouchArea(area) => self.touch_areas.push(area),
+            Binding::TouchDrag => self.touch_drag = true,
+            Binding::Custom(input) => {
+                self.custom.insert(input);
+            }
+            Binding::Action(_) => (),
             Binding::AnyKey => self.any_key = true,
             Binding::None => (),
         }
@@ -418,16 +1072,114 @@ impl IgnoredInputs {
 
     fn clear(&mut self) {
         self.keys.clear();
+        self.logical_keys.clear();
         self.mod_keys = ModKeys::empty();
         self.mouse_buttons.clear();
         self.mouse_motion = false;
         self.mouse_wheel = false;
+        self.wheel_notches.clear();
+        self.cursor_position = false;
         self.gamepad_buttons.clear();
         self.gamepad_axes.clear();
+        self.gamepad_axis_directions.clear();
+        self.mod_buttons.clear();
+        self.touch_areas.clear();
+        self.any_touch = false;
+        self.touch_drag = false;
+        self.custom.clear();
         self.any_key = false;
+        self.devices = InputDevices::empty();
+    }
+
+    fn add_mod_buttons(&mut self, gamepad: GamepadDevice, mod_buttons: ModButtons) {
+        if !mod_buttons.is_empty() {
+            self.mod_buttons
+                .entry(gamepad)
+                .or_default()
+                .insert(mod_buttons);
+        }
+    }
+
+    fn mod_buttons_ignored(&self, gamepad: GamepadDevice, mod_buttons: ModButtons) -> bool {
+        self.mod_buttons
+            .get(&gamepad)
+            .is_some_and(|ignored| ignored.intersects(mod_buttons))
+    }
+
+    /// Returns `true` if the whole axis or any of its directions are ignored.
+    fn gamepad_axis_ignored(&self, gamepad: GamepadDevice, axis: GamepadAxis) -> bool {
+        let input = GamepadInput {
+            gamepad,
+            input: axis,
+        };
+        self.gamepad_axes.contains(&input)
+            || [true, false].into_iter().any(|positive| {
+                let input = GamepadInput {
+                    gamepad,
+                    input: (axis, positive),
+                };
+                self.gamepad_axis_directions.contains(&input)
+            })
     }
 }
 
+/// Returns devices that need to be available to read the binding, including its modifiers.
+fn binding_devices(binding: &Binding) -> InputDevices {
+    let mut devices = match binding {
+        Binding::Keyboard { .. } | Binding::LogicalKey { .. } => InputDevices::KEYBOARD,
+        Binding::MouseButton { .. }
+        | Binding::MouseMotion { .. }
+        | Binding::MouseWheel { .. }
+        | Binding::MouseWheelNotch { .. } => InputDevices::MOUSE,
+        Binding::CursorPosition { .. } => InputDevices::MOUSE,
+        Binding::GamepadButton { .. }
+        | Binding::GamepadAxis { .. }
+        | Binding::GamepadAxisDirection { .. }
+        | Binding::GamepadStick(_) => InputDevices::GAMEPAD,
+        Binding::AnyTouch | Binding::TouchArea(_) | Binding::TouchDrag => InputDevices::TOUCH,
+        Binding::Custom(_) => InputDevices::CUSTOM,
+        // Individual buttons are checked separately.
+        Binding::Action(_) | Binding::AnyKey | Binding::None => InputDevices::empty(),
+    };
+    if !binding.mod_keys().is_empty() {
+        devices |= InputDevices::KEYBOARD;
+    }
+    if !binding.mod_buttons().is_empty() {
+        devices |= InputDevices::GAMEPAD;
+    }
+
+    devices
+}
+
+/// Presses read from input messages by [`InputReader::read_taps`].
+#[derive(Default)]
+pub(crate) struct Taps {
+    keyboard_cursor: MessageCursor<KeyboardInput>,
+    mouse_button_cursor: MessageCursor<MouseButtonInput>,
+    gamepad_button_cursor: MessageCursor<GamepadButtonStateChangedEvent>,
+    keys: HashSet<KeyCode>,
+    logical_keys: HashSet<Key>,
+    mouse_buttons: HashSet<MouseButton>,
+    gamepad_buttons: HashSet<(Entity, GamepadButton)>,
+}
+
+/// Mouse deltas accumulated across frames by [`InputReader::read_buffered`].
+#[derive(Default)]
+pub(crate) struct InputBuffer {
+    mouse_motion_cursor: MessageCursor<MouseMotion>,
+    mouse_wheel_cursor: MessageCursor<MouseWheel>,
+
+    /// Deltas that weren't yet taken by schedule runs.
+    pending_motion: Vec2,
+    pending_scroll: Vec2,
+    pending_lines: Vec2,
+
+    /// Deltas for the current schedule run.
+    mouse_motion: Vec2,
+    mouse_scroll: Vec2,
+    wheel_notches: WheelNotches,
+}
+
 /// Input and associated device.
 #[derive(Hash, PartialEq, Eq)]
 struct GamepadInput<T: Hash + Eq> {
@@ -438,11 +1190,15 @@ struct GamepadInput<T: Hash + Eq> {
 #[cfg(test)]
 mod tests {
     use bevy::{
-        ecs::system::SystemState,
-        input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        ecs::system::{RunSystemOnce, SystemState},
+        input::{
+            mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+            touch::{TouchPhase, touch_screen_input_system},
+        },
     };
 
     use super::*;
+    use crate::binding::wheel_notch;
 
     #[test]
     fn keyboard() {
@@ -462,6 +1218,46 @@ mod tests {
         assert_eq!(reader.value(Binding::AnyKey), false.into());
     }
 
+    #[test]
+    fn logical_key() {
+        let (mut world, mut state) = init_world();
+
+        let key = Key::Character("z".into());
+        let modifier = KeyCode::ControlLeft;
+        // Physical key that produces the logical key on QWERTZ layout.
+        let physical_key = KeyCode::KeyY;
+        world.resource_mut::<ButtonInput<Key>>().press(key.clone());
+        world.resource_mut::<ButtonInput<KeyCode>>().press(modifier);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(physical_key);
+        world
+            .resource_mut::<KeyLayout>()
+            .insert(physical_key, key.clone());
+
+        let binding = key.with_mod_keys(ModKeys::CONTROL);
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(binding.clone()), true.into());
+        assert_eq!(
+            reader.value(binding.clone().without_mod_keys()),
+            true.into()
+        );
+        assert_eq!(reader.value(Key::Character("y".into())), false.into());
+        assert_eq!(
+            reader.value(binding.clone().with_mod_keys(ModKeys::ALT)),
+            false.into()
+        );
+
+        reader.consume::<PreUpdate>(binding.clone());
+        assert_eq!(reader.value(binding.clone()), false.into());
+        assert_eq!(reader.value(physical_key), false.into());
+
+        reader.clear_consumed::<PreUpdate>();
+        reader.consume::<PreUpdate>(physical_key);
+        assert_eq!(reader.value(physical_key), false.into());
+        assert_eq!(reader.value(binding), false.into());
+    }
+
     #[test]
     fn mouse_button() {
         let (mut world, mut state) = init_world();
@@ -495,13 +1291,13 @@ mod tests {
         let binding = Binding::mouse_motion();
         let mut reader = state.get_mut(&mut world);
         reader.clear_consumed::<PreUpdate>();
-        assert_eq!(reader.value(binding), value.into());
+        assert_eq!(reader.value(binding.clone()), value.into());
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::SHIFT)),
+            reader.value(binding.clone().with_mod_keys(ModKeys::SHIFT)),
             Vec2::ZERO.into()
         );
 
-        reader.consume::<PreUpdate>(binding);
+        reader.consume::<PreUpdate>(binding.clone());
         assert_eq!(reader.value(binding), Vec2::ZERO.into());
     }
 
@@ -518,16 +1314,92 @@ mod tests {
         let binding = Binding::mouse_wheel();
         let mut reader = state.get_mut(&mut world);
         reader.clear_consumed::<PreUpdate>();
-        assert_eq!(reader.value(binding), value.into());
+        assert_eq!(reader.value(binding.clone()), value.into());
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::SUPER)),
+            reader.value(binding.clone().with_mod_keys(ModKeys::SUPER)),
             Vec2::ZERO.into()
         );
 
-        reader.consume::<PreUpdate>(binding);
+        reader.consume::<PreUpdate>(binding.clone());
         assert_eq!(reader.value(binding), Vec2::ZERO.into());
     }
 
+    #[test]
+    fn mouse_wheel_notch() {
+        let (mut world, mut state) = init_world();
+
+        world.insert_resource(AccumulatedMouseScroll {
+            unit: MouseScrollUnit::Line,
+            delta: Vec2::Y,
+        });
+        world.run_system_once(wheel_notch::update).unwrap();
+
+        let up = Binding::from(WheelDirection::Up);
+        let down = Binding::from(WheelDirection::Down);
+        let mut reader = state.get_mut(&mut world);
+        reader.clear_consumed::<PreUpdate>();
+        assert_eq!(reader.value(up.clone()), true.into());
+        assert_eq!(reader.value(down.clone()), false.into());
+        assert_eq!(
+            reader.value(up.clone().with_mod_keys(ModKeys::SUPER)),
+            false.into()
+        );
+
+        reader.consume::<PreUpdate>(down);
+        assert_eq!(
+            reader.value(up.clone()),
+            true.into(),
+            "directions should be consumed independently"
+        );
+        assert_eq!(reader.value(Binding::mouse_wheel()), Vec2::ZERO.into());
+
+        reader.consume::<PreUpdate>(up.clone());
+        assert_eq!(reader.value(up), false.into());
+    }
+
+    #[cfg(feature = "window")]
+    #[test]
+    fn cursor_position() {
+        let (mut world, mut state) = init_world();
+
+        let mut window = Window::default();
+        window.resolution.set(200.0, 100.0);
+        window.set_cursor_position(Some(Vec2::new(50.0, 50.0)));
+        world.spawn((window, PrimaryWindow));
+
+        let binding = Binding::cursor_position();
+        let mut reader = state.get_mut(&mut world);
+        reader.clear_consumed::<PreUpdate>();
+        assert_eq!(reader.value(binding.clone()), Vec2::new(50.0, 50.0).into());
+        assert_eq!(
+            reader.value(Binding::normalized_cursor_position()),
+            Vec2::new(0.25, 0.5).into()
+        );
+
+        reader.consume::<PreUpdate>(binding.clone());
+        assert_eq!(reader.value(binding), Vec2::ZERO.into());
+    }
+
+    #[cfg(feature = "window")]
+    #[test]
+    fn context_window() {
+        let (mut world, mut state) = init_world();
+
+        world.spawn((Window::default(), PrimaryWindow));
+        let mut window = Window::default();
+        let value = Vec2::new(10.0, 20.0);
+        window.set_cursor_position(Some(value));
+        let window = world.spawn(window).id();
+
+        let binding = Binding::cursor_position();
+        let mut reader = state.get_mut(&mut world);
+        reader.clear_consumed::<PreUpdate>();
+        assert_eq!(reader.value(binding.clone()), Vec2::ZERO.into());
+
+        reader.set_window(Some(window));
+        assert_eq!(reader.value(binding), value.into());
+    }
+
     #[test]
     fn gamepad_button() {
         let (mut world, mut state) = init_world();
@@ -593,6 +1465,48 @@ mod tests {
         assert_eq!(reader.value(Binding::AnyKey), false.into());
     }
 
+    #[test]
+    fn gamepad_button_with_mod_buttons() {
+        let (mut world, mut state) = init_world();
+
+        let button = GamepadButton::South;
+        let modifier = GamepadButton::LeftTrigger;
+        let mut gamepad1 = Gamepad::default();
+        gamepad1.analog_mut().set(button, 1.0);
+        let gamepad1 = world.spawn(gamepad1).id();
+
+        let mut gamepad2 = Gamepad::default();
+        gamepad2.digital_mut().press(modifier);
+        world.spawn(gamepad2);
+
+        let binding = button.with_mod_buttons(modifier.into());
+        let mut reader = state.get_mut(&mut world);
+        reader.clear_consumed::<PreUpdate>();
+        assert_eq!(reader.value(button), 1.0.into());
+        assert_eq!(
+            reader.value(binding.clone()),
+            0.0.into(),
+            "modifier should be held on the same gamepad"
+        );
+
+        world
+            .get_mut::<Gamepad>(gamepad1)
+            .unwrap()
+            .digital_mut()
+            .press(modifier);
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(binding.clone()), 1.0.into());
+
+        reader.consume::<PreUpdate>(binding.clone());
+        assert_eq!(reader.value(binding), 0.0.into());
+        assert_eq!(
+            reader.value(GamepadAxis::LeftStickX.with_mod_buttons(modifier.into())),
+            0.0.into(),
+            "modifier should be consumed too"
+        );
+    }
+
     #[test]
     fn gamepad_axis() {
         let (mut world, mut state) = init_world();
@@ -649,6 +1563,115 @@ mod tests {
         assert_eq!(reader.value(axis2), 0.0.into());
     }
 
+    #[test]
+    fn gamepad_stick() {
+        let (mut world, mut state) = init_world();
+
+        let value = Vec2::new(0.5, 1.0);
+        let stick = GamepadStick::Left;
+        let [x, y] = stick.axes();
+        let mut gamepad1 = Gamepad::default();
+        gamepad1.analog_mut().set(x, value.x);
+        gamepad1.analog_mut().set(y, value.y);
+        let gamepad_entity = world.spawn(gamepad1).id();
+
+        let mut gamepad2 = Gamepad::default();
+        gamepad2.analog_mut().set(GamepadAxis::RightStickX, 1.0);
+        world.spawn(gamepad2);
+
+        let mut reader = state.get_mut(&mut world);
+        reader.set_gamepad(gamepad_entity);
+        assert_eq!(reader.value(stick), value.into());
+        assert_eq!(
+            reader.value(GamepadStick::Right),
+            Vec2::ZERO.into(),
+            "should read only from `{gamepad_entity:?}`"
+        );
+
+        reader.consume::<PreUpdate>(stick);
+        assert_eq!(reader.value(stick), Vec2::ZERO.into());
+        assert_eq!(reader.value(x), 0.0.into());
+        assert_eq!(reader.value(y), 0.0.into());
+    }
+
+    #[test]
+    fn any_gamepad_stick() {
+        let (mut world, mut state) = init_world();
+
+        let stick = GamepadStick::Right;
+        let [x, y] = stick.axes();
+        let mut gamepad1 = Gamepad::default();
+        gamepad1.analog_mut().set(x, 0.5);
+        world.spawn(gamepad1);
+
+        let mut gamepad2 = Gamepad::default();
+        gamepad2.analog_mut().set(y, 0.5);
+        world.spawn(gamepad2);
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(stick), Vec2::splat(0.5).into());
+        assert_eq!(reader.value(GamepadStick::Left), Vec2::ZERO.into());
+
+        reader.consume::<PreUpdate>(y);
+        assert_eq!(
+            reader.value(stick),
+            Vec2::ZERO.into(),
+            "consuming one axis should consume the stick"
+        );
+        assert_eq!(reader.value(x), 0.5.into());
+    }
+
+    #[test]
+    fn gamepad_axis_direction() {
+        let (mut world, mut state) = init_world();
+
+        let value = -0.8;
+        let axis = GamepadAxis::LeftStickX;
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(axis, value);
+        world.spawn(gamepad);
+
+        let positive = Binding::gamepad_axis_direction(axis, true);
+        let negative = Binding::gamepad_axis_direction(axis, false);
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(positive.clone()), false.into());
+        assert_eq!(reader.value(negative.clone()), true.into());
+        assert_eq!(
+            reader.value(Binding::GamepadAxisDirection {
+                axis,
+                positive: false,
+                threshold: 0.9,
+            }),
+            false.into()
+        );
+
+        reader.consume::<PreUpdate>(positive);
+        assert_eq!(
+            reader.value(negative.clone()),
+            true.into(),
+            "opposite direction should be consumed independently"
+        );
+        assert_eq!(reader.value(axis), 0.0.into());
+
+        reader.consume::<PreUpdate>(negative.clone());
+        assert_eq!(reader.value(negative), false.into());
+    }
+
+    #[test]
+    fn gamepad_axis_direction_with_axis() {
+        let (mut world, mut state) = init_world();
+
+        let axis = GamepadAxis::LeftStickX;
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(axis, 1.0);
+        world.spawn(gamepad);
+
+        let direction = Binding::gamepad_axis_direction(axis, true);
+        let mut reader = state.get_mut(&mut world);
+        reader.consume::<PreUpdate>(axis);
+        assert_eq!(reader.value(direction), false.into());
+    }
+
     #[test]
     fn no_gamepad() {
         let (mut world, mut state) = init_world();
@@ -666,6 +1689,7 @@ mod tests {
         reader.set_gamepad(None);
         assert_eq!(reader.value(button), 0.0.into());
         assert_eq!(reader.value(axis), 0.0.into());
+        assert_eq!(reader.value(GamepadStick::Left), Vec2::ZERO.into());
         assert_eq!(reader.value(Binding::AnyKey), false.into());
     }
 
@@ -690,6 +1714,83 @@ mod tests {
         assert_eq!(reader.value(axis), 0.0.into());
     }
 
+    #[test]
+    fn any_touch() {
+        let (mut world, mut state) = init_world();
+
+        let area = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let binding = Binding::TouchArea(area);
+
+        let reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(Binding::AnyTouch), false.into());
+        assert_eq!(reader.value(binding.clone()), false.into());
+
+        touch(&mut world, 0, TouchPhase::Started, Vec2::splat(5.0));
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(Binding::AnyTouch), true.into());
+        assert_eq!(reader.value(binding.clone()), true.into());
+
+        reader.consume::<PreUpdate>(Binding::AnyTouch);
+        assert_eq!(reader.value(Binding::AnyTouch), false.into());
+        assert_eq!(reader.value(binding), false.into());
+    }
+
+    #[test]
+    fn touch_area() {
+        let (mut world, mut state) = init_world();
+
+        let area1 = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let area2 = Rect::new(20.0, 20.0, 30.0, 30.0);
+        touch(&mut world, 0, TouchPhase::Started, Vec2::splat(5.0));
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(Binding::TouchArea(area1)), true.into());
+        assert_eq!(reader.value(Binding::TouchArea(area2)), false.into());
+
+        reader.consume::<PreUpdate>(Binding::TouchArea(area1));
+        assert_eq!(reader.value(Binding::TouchArea(area1)), false.into());
+        assert_eq!(
+            reader.value(Binding::AnyTouch),
+            false.into(),
+            "the only touch is inside the consumed area"
+        );
+
+        touch(&mut world, 1, TouchPhase::Started, Vec2::splat(25.0));
+
+        let reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(Binding::TouchArea(area1)), false.into());
+        assert_eq!(reader.value(Binding::TouchArea(area2)), true.into());
+        assert_eq!(reader.value(Binding::AnyTouch), true.into());
+    }
+
+    #[test]
+    fn touch_drag() {
+        let (mut world, mut state) = init_world();
+
+        touch(&mut world, 0, TouchPhase::Started, Vec2::ZERO);
+        touch(&mut world, 1, TouchPhase::Started, Vec2::ZERO);
+        world.write_message(TouchInput {
+            phase: TouchPhase::Moved,
+            position: Vec2::new(2.0, 0.0),
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id: 0,
+        });
+        touch(&mut world, 1, TouchPhase::Moved, Vec2::new(0.0, 2.0));
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(Binding::TouchDrag), Vec2::ONE.into());
+
+        reader.consume::<PreUpdate>(Binding::TouchDrag);
+        assert_eq!(reader.value(Binding::TouchDrag), Vec2::ZERO.into());
+        assert_eq!(
+            reader.value(Binding::AnyTouch),
+            true.into(),
+            "drag shouldn't consume touches"
+        );
+    }
+
     #[test]
     fn keyboard_with_modifier() {
         let (mut world, mut state) = init_world();
@@ -702,19 +1803,23 @@ mod tests {
 
         let binding = key.with_mod_keys(modifier.into());
         let mut reader = state.get_mut(&mut world);
-        assert_eq!(reader.value(binding), true.into());
+        assert_eq!(reader.value(binding.clone()), true.into());
         assert_eq!(reader.value(key), true.into());
         assert_eq!(reader.value(Binding::AnyKey), true.into());
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::ALT)),
+            reader.value(binding.clone().with_mod_keys(ModKeys::ALT)),
             false.into()
         );
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::CONTROL | ModKeys::ALT)),
+            reader.value(
+                binding
+                    .clone()
+                    .with_mod_keys(ModKeys::CONTROL | ModKeys::ALT)
+            ),
             false.into()
         );
 
-        reader.consume::<PreUpdate>(binding);
+        reader.consume::<PreUpdate>(binding.clone());
         assert_eq!(reader.value(binding), false.into());
         assert_eq!(
             reader.value(Binding::AnyKey),
@@ -733,6 +1838,103 @@ mod tests {
         assert_eq!(reader.value(other_key), true.into());
     }
 
+    #[test]
+    fn keyboard_with_sided_modifier() {
+        let (mut world, mut state) = init_world();
+
+        let key = KeyCode::KeyA;
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::ShiftRight);
+        keys.press(key);
+
+        let binding = key.with_mod_keys(ModKeys::SHIFT_RIGHT);
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(binding.clone()), true.into());
+        assert_eq!(reader.value(key.with_mod_keys(ModKeys::SHIFT)), true.into());
+        assert_eq!(
+            reader.value(key.with_mod_keys(ModKeys::SHIFT_LEFT)),
+            false.into()
+        );
+
+        reader.consume::<PreUpdate>(binding);
+
+        let other_key = KeyCode::KeyB;
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(other_key);
+
+        let reader = state.get_mut(&mut world);
+        assert_eq!(
+            reader.value(other_key.with_mod_keys(ModKeys::SHIFT)),
+            false.into(),
+            "should be consumed while the right side is held"
+        );
+
+        // Switch to the modifier from the opposite side.
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::ShiftRight);
+        keys.press(KeyCode::ShiftLeft);
+
+        let reader = state.get_mut(&mut world);
+        assert_eq!(
+            reader.value(other_key.with_mod_keys(ModKeys::SHIFT_LEFT)),
+            true.into()
+        );
+        assert_eq!(
+            reader.value(other_key.with_mod_keys(ModKeys::SHIFT)),
+            true.into(),
+            "only the right side should be consumed"
+        );
+    }
+
+    #[test]
+    fn exact_mod_keys() {
+        let (mut world, mut state) = init_world();
+
+        let key = KeyCode::KeyS;
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::ControlLeft);
+        keys.press(key);
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(key), true.into());
+
+        reader.set_exact_mod_keys(true);
+        assert_eq!(reader.value(key), false.into());
+        assert_eq!(
+            reader.value(key.with_mod_keys(ModKeys::CONTROL)),
+            true.into()
+        );
+        assert_eq!(
+            reader.value(key.with_mod_keys(ModKeys::CONTROL_LEFT)),
+            true.into()
+        );
+        assert_eq!(
+            reader.value(KeyCode::ControlLeft),
+            true.into(),
+            "modifier itself shouldn't be treated as extra"
+        );
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftRight);
+
+        let mut reader = state.get_mut(&mut world);
+        reader.set_exact_mod_keys(true);
+        assert_eq!(
+            reader.value(key.with_mod_keys(ModKeys::CONTROL)),
+            false.into()
+        );
+        assert_eq!(
+            reader.value(key.with_mod_keys(ModKeys::CONTROL | ModKeys::SHIFT)),
+            true.into()
+        );
+        assert_eq!(
+            reader.value(key.with_mod_keys(ModKeys::CONTROL | ModKeys::SHIFT_LEFT)),
+            false.into()
+        );
+    }
+
     #[test]
     fn mouse_button_with_modifier() {
         let (mut world, mut state) = init_world();
@@ -746,19 +1948,23 @@ mod tests {
 
         let binding = button.with_mod_keys(modifier.into());
         let mut reader = state.get_mut(&mut world);
-        assert_eq!(reader.value(binding), true.into());
+        assert_eq!(reader.value(binding.clone()), true.into());
         assert_eq!(reader.value(button), true.into());
         assert_eq!(reader.value(Binding::AnyKey), true.into());
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::CONTROL)),
+            reader.value(binding.clone().with_mod_keys(ModKeys::CONTROL)),
             false.into()
         );
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::CONTROL | ModKeys::ALT)),
+            reader.value(
+                binding
+                    .clone()
+                    .with_mod_keys(ModKeys::CONTROL | ModKeys::ALT)
+            ),
             false.into()
         );
 
-        reader.consume::<PreUpdate>(binding);
+        reader.consume::<PreUpdate>(binding.clone());
         assert_eq!(reader.value(binding), false.into());
         assert_eq!(
             reader.value(Binding::AnyKey),
@@ -779,18 +1985,25 @@ mod tests {
         let binding = Binding::mouse_motion().with_mod_keys(modifier.into());
         let mut reader = state.get_mut(&mut world);
         reader.clear_consumed::<PreUpdate>();
-        assert_eq!(reader.value(binding), value.into());
-        assert_eq!(reader.value(binding.without_mod_keys()), value.into());
+        assert_eq!(reader.value(binding.clone()), value.into());
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::SUPER)),
+            reader.value(binding.clone().without_mod_keys()),
+            value.into()
+        );
+        assert_eq!(
+            reader.value(binding.clone().with_mod_keys(ModKeys::SUPER)),
             Vec2::ZERO.into()
         );
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::SHIFT | ModKeys::SUPER)),
+            reader.value(
+                binding
+                    .clone()
+                    .with_mod_keys(ModKeys::SHIFT | ModKeys::SUPER)
+            ),
             Vec2::ZERO.into()
         );
 
-        reader.consume::<PreUpdate>(binding);
+        reader.consume::<PreUpdate>(binding.clone());
         assert_eq!(reader.value(binding), Vec2::ZERO.into());
     }
 
@@ -809,18 +2022,25 @@ mod tests {
         let binding = Binding::mouse_wheel().with_mod_keys(modifier.into());
         let mut reader = state.get_mut(&mut world);
         reader.clear_consumed::<PreUpdate>();
-        assert_eq!(reader.value(binding), value.into());
-        assert_eq!(reader.value(binding.without_mod_keys()), value.into());
+        assert_eq!(reader.value(binding.clone()), value.into());
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::SHIFT)),
+            reader.value(binding.clone().without_mod_keys()),
+            value.into()
+        );
+        assert_eq!(
+            reader.value(binding.clone().with_mod_keys(ModKeys::SHIFT)),
             Vec2::ZERO.into()
         );
         assert_eq!(
-            reader.value(binding.with_mod_keys(ModKeys::SHIFT | ModKeys::SUPER)),
+            reader.value(
+                binding
+                    .clone()
+                    .with_mod_keys(ModKeys::SHIFT | ModKeys::SUPER)
+            ),
             Vec2::ZERO.into()
         );
 
-        reader.consume::<PreUpdate>(binding);
+        reader.consume::<PreUpdate>(binding.clone());
         assert_eq!(reader.value(binding), Vec2::ZERO.into());
     }
 
@@ -844,6 +2064,8 @@ mod tests {
             delta: Vec2::ONE,
         });
 
+        touch(&mut world, 0, TouchPhase::Started, Vec2::ZERO);
+
         let mut gamepad = Gamepad::default();
         gamepad.analog_mut().set(axis, 1.0);
         gamepad.analog_mut().set(gamepad_button, 1.0);
@@ -856,6 +2078,7 @@ mod tests {
         action_sources.mouse_wheel = false;
         action_sources.gamepad_button = false;
         action_sources.gamepad_axis = false;
+        action_sources.touch = false;
 
         let mut reader = state.get_mut(&mut world);
         reader.clear_consumed::<PreUpdate>();
@@ -866,11 +2089,40 @@ mod tests {
         assert_eq!(reader.value(Binding::mouse_wheel()), Vec2::ZERO.into());
         assert_eq!(reader.value(gamepad_button), 0.0.into());
         assert_eq!(reader.value(axis), 0.0.into());
+        assert_eq!(reader.value(Binding::AnyTouch), false.into());
+    }
+
+    #[cfg(feature = "window")]
+    #[test]
+    fn cursor_position_source() {
+        let (mut world, mut state) = init_world();
+
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::ONE));
+        world.spawn((window, PrimaryWindow));
+        world.resource_mut::<ActionSources>().cursor_position = false;
+
+        let mut reader = state.get_mut(&mut world);
+        reader.clear_consumed::<PreUpdate>();
+        assert_eq!(reader.value(Binding::cursor_position()), Vec2::ZERO.into());
+    }
+
+    fn touch(world: &mut World, id: u64, phase: TouchPhase, position: Vec2) {
+        world.write_message(TouchInput {
+            phase,
+            position,
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id,
+        });
+        world.run_system_once(touch_screen_input_system).unwrap();
+        world.resource_mut::<Messages<TouchInput>>().clear();
     }
 
     fn init_world<'w, 's>() -> (World, SystemState<InputReader<'w, 's>>) {
         let mut world = World::new();
         world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<ButtonInput<Key>>();
         world.init_resource::<ButtonInput<MouseButton>>();
         world.init_resource::<Messages<MouseMotion>>();
         world.init_resource::<Messages<MouseWheel>>();
@@ -878,9 +2130,16 @@ mod tests {
         world.init_resource::<Axis<GamepadAxis>>();
         world.init_resource::<AccumulatedMouseMotion>();
         world.init_resource::<AccumulatedMouseScroll>();
+        world.init_resource::<Messages<TouchInput>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<KeyLayout>();
         world.init_resource::<ConsumedInputs>();
         world.init_resource::<PendingBindings>();
         world.init_resource::<ActionSources>();
+        world.init_resource::<CustomSources>();
+        world.init_resource::<SourceValues>();
+        world.init_resource::<WheelNotchSettings>();
+        world.init_resource::<WheelNotches>();
 
         let state = SystemState::<InputReader>::new(&mut world);
 
@@ -28,13 +28,33 @@ impl<S: ScheduleLabel> ContextInstances<S> {
         index
     }
 
+    /// Moves an existing instance to match the new priority.
+    ///
+    /// Returns `false` if there is no instance for the entity.
+    pub(super) fn reorder<C: Component>(&mut self, entity: Entity, priority: usize) -> bool {
+        let Some(index) = self.position::<C>(entity) else {
+            return false;
+        };
+
+        let mut instance = self.instances.remove(index);
+        instance.priority = priority;
+        let index = self.partition_point(|i| i.priority > instance.priority);
+        self.instances.insert(index, instance);
+
+        true
+    }
+
     pub(super) fn remove<C: Component>(&mut self, entity: Entity) {
         let index = self
-            .iter()
-            .position(|inst| inst.entity == entity && inst.type_id == TypeId::of::<C>())
+            .position::<C>(entity)
             .expect("context instance should be created before removal");
         self.instances.remove(index);
     }
+
+    fn position<C: Component>(&self, entity: Entity) -> Option<usize> {
+        self.iter()
+            .position(|inst| inst.entity == entity && inst.type_id == TypeId::of::<C>())
+    }
 }
 
 /// Meta information for context on an entity.
@@ -44,6 +64,7 @@ pub(crate) struct ContextInstance {
     type_id: TypeId,
     priority: usize,
     is_active: fn(&Self, &FilteredEntityRef) -> bool,
+    blocking: fn(&Self, &FilteredEntityRef) -> Option<InputBlocking>,
     actions: for<'a> fn(&Self, &'a FilteredEntityRef) -> Option<&'a [Entity]>,
     actions_mut: for<'a> fn(&Self, &'a mut FilteredEntityMut) -> Option<Mut<'a, [Entity]>>,
 }
@@ -58,6 +79,7 @@ impl ContextInstance {
             type_id: TypeId::of::<C>(),
             priority,
             is_active: Self::is_active_typed::<C>,
+            blocking: Self::blocking_typed::<C>,
             actions: Self::actions_typed::<C>,
             actions_mut: Self::actions_mut_typed::<C>,
         }
@@ -68,6 +90,11 @@ impl ContextInstance {
         (self.is_active)(self, context)
     }
 
+    /// Returns the value from [`ContextBlocking<C>`].
+    pub(super) fn blocking(&self, context: &FilteredEntityRef) -> Option<InputBlocking> {
+        (self.blocking)(self, context)
+    }
+
     /// Returns a reference to entities from [`Actions<C>`], for which this instance was created.
     pub(super) fn actions<'a>(&self, context: &'a FilteredEntityRef) -> Option<&'a [Entity]> {
         (self.actions)(self, context)
@@ -89,6 +116,12 @@ impl ContextInstance {
             .is_some_and(|&active| *active)
     }
 
+    fn blocking_typed<C: Component>(&self, context: &FilteredEntityRef) -> Option<InputBlocking> {
+        context
+            .get::<ContextBlocking<C>>()
+            .map(|&blocking| *blocking)
+    }
+
     fn actions_typed<'a, C: Component>(
         &self,
         context: &'a FilteredEntityRef,
@@ -123,6 +156,21 @@ mod tests {
         assert_eq!(instances.add::<Test>(Entity::PLACEHOLDER, 0), 4);
     }
 
+    #[test]
+    fn reorder() {
+        let mut instances = ContextInstances::<PreUpdate>::default();
+        let first = Entity::from_raw_u32(1).unwrap();
+        let second = Entity::from_raw_u32(2).unwrap();
+        instances.add::<Test>(first, 1);
+        instances.add::<Test>(second, 0);
+
+        assert!(instances.reorder::<Test>(second, 2));
+        let entities: Vec<_> = instances.iter().map(|i| i.entity).collect();
+        assert_eq!(entities, [second, first]);
+
+        assert!(!instances.reorder::<Test>(Entity::PLACEHOLDER, 2));
+    }
+
     #[derive(Component)]
     struct Test;
 }
@@ -0,0 +1,381 @@
+/*!
+Stack of input contexts with push and pop semantics.
+
+Menus, dialogs and vehicle modes usually form a stack: opening a dialog on top of a pause menu
+should route input to the dialog, and closing it should return input to the menu.
+[`ContextStack`] manages [`ContextActivity`] and [`ContextPriority`] of the pushed contexts
+automatically.
+
+Each pushed layer is evaluated before the layers below it. A layer pushed via
+[`ContextStackCommandsExt::push_blocking_context`] also gets [`ContextBlocking::ALL`], so all contexts
+evaluated after it, including the layers below, can't read any input until it's popped.
+Blocked layers stay active and keep the internal state of their conditions and modifiers, but their
+actions are evaluated without input: held actions will be completed or canceled and triggered again
+after the pop if the input is still held.
+
+Pushing and popping triggers [`ContextPushed`] and [`ContextPopped`] on the context entity.
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .add_input_context::<Player>()
+    .add_input_context::<Menu>()
+    .add_observer(open_menu);
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct OpenMenu;
+
+fn open_menu(_fire: On<Fire<OpenMenu>>, mut commands: Commands) {
+    let menu = commands.spawn(Menu).id();
+    commands.push_blocking_context::<Menu>(menu);
+}
+```
+*/
+
+use core::any::TypeId;
+
+use bevy::prelude::*;
+use log::{debug, warn};
+
+use crate::prelude::*;
+
+/// Contexts pushed via [`ContextStackCommandsExt`], from bottom to top.
+///
+/// Each layer gets [`Self::base_priority`] plus its index as [`ContextPriority`]
+/// and is active while it's in the stack. On pop, both are restored to their values before the push.
+#[derive(Resource, Default)]
+pub struct ContextStack {
+    layers: Vec<StackLayer>,
+
+    /// Priority of the bottom layer.
+    ///
+    /// Can be used to place the stack above contexts that aren't managed by it.
+    ///
+    /// By default set to 0.
+    pub base_priority: usize,
+}
+
+impl ContextStack {
+    /// Returns all layers, from bottom to top.
+    #[must_use]
+    pub fn layers(&self) -> &[StackLayer] {
+        &self.layers
+    }
+
+    /// Returns the topmost layer.
+    #[must_use]
+    pub fn top(&self) -> Option<&StackLayer> {
+        self.layers.last()
+    }
+
+    /// Returns `true` if context `C` on the entity is in the stack.
+    #[must_use]
+    pub fn contains<C: Component>(&self, entity: Entity) -> bool {
+        self.position(entity, TypeId::of::<C>()).is_some()
+    }
+
+    /// Returns the number of layers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` if nothing is pushed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    fn position(&self, entity: Entity, type_id: TypeId) -> Option<usize> {
+        self.layers
+            .iter()
+            .position(|layer| layer.entity == entity && layer.type_id == type_id)
+    }
+
+    /// Updates activity and priority of all layers.
+    fn apply(&self, world: &mut World) {
+        for (index, layer) in self.layers.iter().enumerate() {
+            (layer.set_priority)(world, layer.entity, self.base_priority + index);
+            (layer.set_activity)(world, layer.entity, true);
+        }
+    }
+}
+
+/// Context on an entity, pushed to [`ContextStack`].
+#[derive(Debug, Clone, Copy)]
+pub struct StackLayer {
+    entity: Entity,
+    type_id: TypeId,
+    name: ShortName<'static>,
+    blocking: bool,
+
+    /// Activity before the push to restore it on pop.
+    previous_activity: bool,
+
+    /// Priority before the push to restore it on pop.
+    previous_priority: usize,
+
+    /// Blocking before the push to restore it on pop.
+    previous_blocking: Option<InputBlocking>,
+
+    set_activity: fn(&mut World, Entity, bool),
+    set_priority: fn(&mut World, Entity, usize),
+    set_blocking: fn(&mut World, Entity, Option<InputBlocking>),
+}
+
+impl StackLayer {
+    fn new<C: Component>(
+        entity: Entity,
+        blocking: bool,
+        previous_activity: bool,
+        previous_priority: usize,
+        previous_blocking: Option<InputBlocking>,
+    ) -> Self {
+        Self {
+            entity,
+            type_id: TypeId::of::<C>(),
+            name: ShortName::of::<C>(),
+            blocking,
+            previous_activity,
+            previous_priority,
+            previous_blocking,
+            set_activity: set_activity::<C>,
+            set_priority: set_priority::<C>,
+            set_blocking: set_blocking::<C>,
+        }
+    }
+
+    /// Returns the entity with the context.
+    #[must_use]
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns `true` if the layer is context `C`.
+    #[must_use]
+    pub fn is<C: Component>(&self) -> bool {
+        self.type_id == TypeId::of::<C>()
+    }
+
+    /// Returns the short type name of the context.
+    #[must_use]
+    pub fn name(&self) -> ShortName<'static> {
+        self.name
+    }
+
+    /// Returns `true` if the layer blocks inputs for all layers below it.
+    #[must_use]
+    pub fn blocking(&self) -> bool {
+        self.blocking
+    }
+}
+
+fn set_activity<C: Component>(world: &mut World, entity: Entity, active: bool) {
+    let Ok(mut context) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if context
+        .get::<ContextActivity<C>>()
+        .is_some_and(|activity| **activity == active)
+    {
+        return;
+    }
+    context.insert(ContextActivity::<C>::new(active).with_reason(ActivityReason::Stack));
+}
+
+fn set_priority<C: Component>(world: &mut World, entity: Entity, priority: usize) {
+    let Ok(mut context) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if context
+        .get::<ContextPriority<C>>()
+        .is_some_and(|current| **current == priority)
+    {
+        return;
+    }
+    context.insert(ContextPriority::<C>::new(priority));
+}
+
+fn set_blocking<C: Component>(world: &mut World, entity: Entity, blocking: Option<InputBlocking>) {
+    let Ok(mut context) = world.get_entity_mut(entity) else {
+        return;
+    };
+    match blocking {
+        Some(blocking) => {
+            context.insert(ContextBlocking::<C>::new(blocking));
+        }
+        None => {
+            context.remove::<ContextBlocking<C>>();
+        }
+    }
+}
+
+/// Extension trait for [`Commands`] to push and pop contexts from [`ContextStack`].
+pub trait ContextStackCommandsExt {
+    /// Pushes context `C` on the entity to the top of [`ContextStack`].
+    ///
+    /// The context will be activated and evaluated before all other layers.
+    /// Layers below it stay active.
+    ///
+    /// Triggers [`ContextPushed`].
+    fn push_context<C: Component>(&mut self, entity: Entity) -> &mut Self;
+
+    /// Like [`Self::push_context`], but also blocks inputs for all layers below it until it's popped.
+    ///
+    /// Inserts [`ContextBlocking::ALL`] for the context and restores the previous value on pop.
+    /// See the [module docs](self) for details.
+    fn push_blocking_context<C: Component>(&mut self, entity: Entity) -> &mut Self;
+
+    /// Removes the topmost layer from [`ContextStack`].
+    ///
+    /// The popped context will get back its activity and priority from before the push.
+    /// Layers that were blocked by it will receive inputs again.
+    ///
+    /// Triggers [`ContextPopped`].
+    fn pop_context(&mut self) -> &mut Self;
+}
+
+impl ContextStackCommandsExt for Commands<'_, '_> {
+    fn push_context<C: Component>(&mut self, entity: Entity) -> &mut Self {
+        self.queue(move |world: &mut World| push::<C>(world, entity, false));
+        self
+    }
+
+    fn push_blocking_context<C: Component>(&mut self, entity: Entity) -> &mut Self {
+        self.queue(move |world: &mut World| push::<C>(world, entity, true));
+        self
+    }
+
+    fn pop_context(&mut self) -> &mut Self {
+        self.queue(pop);
+        self
+    }
+}
+
+fn push<C: Component>(world: &mut World, entity: Entity, blocking: bool) {
+    let Ok(context) = world.get_entity(entity) else {
+        warn!(
+            "can't push `{}` for despawned `{entity}`",
+            ShortName::of::<C>()
+        );
+        return;
+    };
+    let previous_activity = context
+        .get::<ContextActivity<C>>()
+        .is_none_or(|activity| **activity);
+    let previous_blocking = context
+        .get::<ContextBlocking<C>>()
+        .map(|blocking| **blocking);
+    let Some(&priority) = context.get::<ContextPriority<C>>() else {
+        warn!(
+            "can't push `{}` to `{entity}` without the context",
+            ShortName::of::<C>()
+        );
+        return;
+    };
+
+    let pushed = world.resource_scope(|world, mut stack: Mut<ContextStack>| {
+        if stack.contains::<C>(entity) {
+            warn!(
+                "ignoring push of `{}` on `{entity}` that is already in the stack",
+                ShortName::of::<C>()
+            );
+            return None;
+        }
+
+        debug!(
+            "pushing `{}` on `{entity}` with blocking `{blocking}`",
+            ShortName::of::<C>()
+        );
+        let layer = StackLayer::new::<C>(
+            entity,
+            blocking,
+            previous_activity,
+            *priority,
+            previous_blocking,
+        );
+        if blocking {
+            (layer.set_blocking)(world, entity, Some(InputBlocking::All));
+        }
+        stack.layers.push(layer);
+        stack.apply(world);
+
+        Some(ContextPushed {
+            entity,
+            layer,
+            depth: stack.len() - 1,
+        })
+    });
+
+    // Trigger outside of the scope to let observers access the stack.
+    if let Some(pushed) = pushed {
+        world.trigger(pushed);
+    }
+}
+
+fn pop(world: &mut World) {
+    let popped = world.resource_scope(|world, mut stack: Mut<ContextStack>| {
+        let Some(layer) = stack.layers.pop() else {
+            warn!("ignoring pop from the empty context stack");
+            return None;
+        };
+
+        debug!("popping `{}` on `{}`", layer.name, layer.entity);
+        (layer.set_activity)(world, layer.entity, layer.previous_activity);
+        (layer.set_priority)(world, layer.entity, layer.previous_priority);
+        if layer.blocking {
+            (layer.set_blocking)(world, layer.entity, layer.previous_blocking);
+        }
+        stack.apply(world);
+
+        Some(ContextPopped {
+            entity: layer.entity,
+            layer,
+            depth: stack.len(),
+        })
+    });
+
+    if let Some(popped) = popped
+        && world.get_entity(popped.entity).is_ok()
+    {
+        world.trigger(popped);
+    }
+}
+
+/// Triggered on the context entity when its context is pushed to [`ContextStack`].
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct ContextPushed {
+    /// Entity with the pushed context.
+    pub entity: Entity,
+
+    /// Pushed layer.
+    pub layer: StackLayer,
+
+    /// Index of the layer in the stack.
+    pub depth: usize,
+}
+
+/// Triggered on the context entity when its context is popped from [`ContextStack`].
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct ContextPopped {
+    /// Entity with the popped context.
+    pub entity: Entity,
+
+    /// Popped layer.
+    pub layer: StackLayer,
+
+    /// Index the layer had in the stack.
+    pub depth: usize,
+}
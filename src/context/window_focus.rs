@@ -0,0 +1,197 @@
+/*!
+Resetting actions when the window loses focus.
+
+Bevy releases all inputs when the window loses focus, but conditions and modifiers keep their
+internal state. For example, a [`Toggle`] stays toggled and a [`Hold`] keeps its timer,
+so actions may stay latched after switching back to the window.
+
+[`WindowFocusPolicy`] configures what happens with actions on [`WindowFocused`]. By default, actions are kept
+as is. Insert it as a resource to configure all contexts, or add it to a context entity to override
+the resource for all input contexts on this entity. Contexts react only to focus changes of their [`ContextWindow`] or the primary window.
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .insert_resource(WindowFocusPolicy::ResetAndRequireRelease)
+    .add_input_context::<Player>()
+    .add_input_context::<Menu>()
+    .finish();
+
+// Keep the menu state when the window loses focus.
+app.world_mut().spawn((Menu, WindowFocusPolicy::Keep));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Menu;
+```
+*/
+
+use bevy::{
+    ecs::world::{EntityMut, FilteredEntityMut},
+    prelude::*,
+    window::{PrimaryWindow, WindowFocused},
+};
+use log::debug;
+
+use crate::{
+    action::fns::ActionFns,
+    condition::fns::ConditionFns,
+    context::{ExternallyMocked, input_reader::PendingBindings},
+    modifier::fns::ModifierFns,
+    prelude::*,
+};
+
+/// Configures what happens with actions when the window loses focus.
+///
+/// Can be inserted as a resource to apply globally, or as a component on a context entity
+/// to override the resource for all input contexts on this entity.
+///
+/// See the [module docs](self) for details.
+#[derive(Resource, Component, Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, Hash, PartialEq, Resource)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WindowFocusPolicy {
+    /// Keep the state of actions, conditions and modifiers.
+    #[default]
+    Keep,
+    /// Reset actions to [`TriggerState::None`] with [`ActionValue::zero`], triggering the corresponding
+    /// events, and reset the internal state of their conditions and modifiers.
+    Reset,
+    /// Like [`Self::Reset`], but when the window regains focus, inputs of all bindings are ignored
+    /// until they become inactive, as if all actions had [`ActionSettings::require_reset`].
+    ///
+    /// Inputs are ignored only for contexts on the entity with this policy, so contexts with other
+    /// policies that share the same inputs aren't affected.
+    ResetAndRequireRelease,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update<C: Component>(
+    mut commands: Commands,
+    mut focus_events: MessageReader<WindowFocused>,
+    global_policy: Res<WindowFocusPolicy>,
+    mut pending: ResMut<PendingBindings>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    contexts: Query<(
+        Entity,
+        &Actions<C>,
+        Option<&ContextWindow>,
+        Option<&WindowFocusPolicy>,
+    )>,
+    mut actions: Query<
+        (
+            Entity,
+            &Name,
+            &ActionFns,
+            Option<&Bindings>,
+            &mut ActionValue,
+            &mut TriggerState,
+            &mut ActionEvents,
+            &mut ActionTime,
+        ),
+        Without<ExternallyMocked>,
+    >,
+    bindings: Query<&Binding>,
+) {
+    for focus_event in focus_events.read() {
+        for (context, context_actions, window, policy) in &contexts {
+            let policy = policy.copied().unwrap_or(*global_policy);
+            if policy == WindowFocusPolicy::Keep {
+                continue;
+            }
+
+            let window = window.map(|w| **w).or_else(|| primary_window.single().ok());
+            if window != Some(focus_event.window) {
+                continue;
+            }
+
+            if focus_event.focused {
+                if policy == WindowFocusPolicy::ResetAndRequireRelease {
+                    for (_, _, _, action_bindings, ..) in actions.iter_many(context_actions) {
+                        pending.extend_for(
+                            context,
+                            bindings
+                                .iter_many(action_bindings.into_iter().flatten())
+                                .cloned(),
+                        );
+                    }
+                }
+                continue;
+            }
+
+            debug!(
+                "resetting actions of `{}` on `{context}` due to focus loss",
+                ShortName::of::<C>()
+            );
+
+            let mut actions_iter = actions.iter_many_mut(context_actions);
+            while let Some((
+                action,
+                name,
+                fns,
+                action_bindings,
+                mut value,
+                mut state,
+                mut events,
+                mut time,
+            )) = actions_iter.fetch_next()
+            {
+                *time = Default::default();
+                events.set_if_neq(ActionEvents::new(*state, TriggerState::None));
+                state.set_if_neq(Default::default());
+                value.set_if_neq(ActionValue::zero(value.dim()));
+
+                fns.trigger(
+                    &mut commands,
+                    context,
+                    action,
+                    name,
+                    *state,
+                    *events,
+                    *value,
+                    *time,
+                );
+
+                commands.queue(reset_state(action));
+                for binding in action_bindings.into_iter().flatten() {
+                    commands.queue(reset_state(binding));
+                }
+            }
+        }
+    }
+}
+
+/// Resets the internal state of all conditions and modifiers on the entity.
+fn reset_state(entity: Entity) -> impl Command {
+    move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        let conditions = entity.get::<ConditionFns>().cloned();
+        let modifiers = entity.get::<ModifierFns>().cloned();
+        let mut entity = EntityMut::from(&mut entity);
+        let mut entity = FilteredEntityMut::from(&mut entity);
+        for get_condition in conditions.iter().flat_map(|fns| fns.iter()) {
+            get_condition(&mut entity).reset();
+        }
+        for get_modifier in modifiers.iter().flat_map(|fns| fns.iter()) {
+            get_modifier(&mut entity).reset();
+        }
+    }
+}
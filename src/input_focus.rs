@@ -0,0 +1,179 @@
+/*!
+Integration with [`bevy_input_focus`](bevy::input_focus).
+
+Add [`InputFocusPolicy`] to a context entity to suppress actions of all input contexts on this entity
+while a UI element holds [`InputFocus`]. For example, typing into a text field won't make the player jump.
+Only inputs from [`InputFocusPolicy::devices`] are suppressed, and they stay available for other contexts.
+
+[`InputFocusPlugin`] initializes focus resources and drives [directional navigation](bevy::input_focus::directional_navigation)
+from the [`NavigateFocus`] action.
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins((EnhancedInputPlugin, InputFocusPlugin))
+    .add_input_context::<Player>()
+    .add_input_context::<Menu>()
+    .finish();
+
+app.world_mut().spawn((
+    Player,
+    InputFocusPolicy::default(),
+    actions!(Player[(Action::<Jump>::new(), bindings![KeyCode::Space])]),
+));
+app.world_mut().spawn((
+    Menu,
+    actions!(Menu[(
+        Action::<NavigateFocus>::new(),
+        Pulse::new(0.25),
+        Bindings::spawn(Cardinal::arrows()),
+    )]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+```
+*/
+
+use bevy::{
+    input_focus::{
+        InputFocus, InputFocusVisible,
+        directional_navigation::{DirectionalNavigationMap, DirectionalNavigationPlugin},
+    },
+    math::CompassOctant,
+    prelude::*,
+};
+use log::debug;
+
+use crate::prelude::*;
+
+/// Integrates input contexts with [`bevy_input_focus`](bevy::input_focus).
+///
+/// See the [module docs](self) for details.
+pub struct InputFocusPlugin;
+
+impl Plugin for InputFocusPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DirectionalNavigationPlugin>() {
+            app.add_plugins(DirectionalNavigationPlugin);
+        }
+
+        app.init_resource::<InputFocus>()
+            .init_resource::<InputFocusVisible>()
+            .add_observer(navigate);
+    }
+}
+
+/// Suppresses actions of all input contexts on this entity while [`InputFocus`] is set.
+///
+/// Read during [`EnhancedInputSystems::Update`].
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputFocusPolicy {
+    /// Devices whose inputs are suppressed.
+    ///
+    /// By default set to [`InputDevices::KEYBOARD`].
+    pub devices: InputDevices,
+
+    /// Suppress only while [`InputFocusVisible`] is `true`.
+    ///
+    /// Useful when the focus is kept after clicking, but should affect actions
+    /// only during keyboard or gamepad navigation.
+    ///
+    /// By default set to `false`.
+    pub require_visible: bool,
+}
+
+impl InputFocusPolicy {
+    /// Creates a new instance that suppresses the given devices.
+    #[must_use]
+    pub const fn new(devices: InputDevices) -> Self {
+        Self {
+            devices,
+            require_visible: false,
+        }
+    }
+
+    /// Sets [`Self::require_visible`].
+    #[must_use]
+    pub const fn with_require_visible(mut self, require_visible: bool) -> Self {
+        self.require_visible = require_visible;
+        self
+    }
+
+    /// Returns devices that need to be suppressed for the current focus.
+    pub(crate) fn suppressed(
+        self,
+        focus: Option<&InputFocus>,
+        visible: Option<&InputFocusVisible>,
+    ) -> InputDevices {
+        let focused = focus.is_some_and(|focus| focus.0.is_some());
+        let visible = visible.is_some_and(|visible| visible.0);
+        if focused && (!self.require_visible || visible) {
+            self.devices
+        } else {
+            InputDevices::empty()
+        }
+    }
+}
+
+impl Default for InputFocusPolicy {
+    fn default() -> Self {
+        Self::new(InputDevices::KEYBOARD)
+    }
+}
+
+/// Moves [`InputFocus`] in the direction of the action value using [`DirectionalNavigationMap`].
+///
+/// Navigates on every [`Fire`], so you most likely want a [`Press`] or [`Pulse`] condition.
+#[derive(Debug)]
+pub struct NavigateFocus;
+
+impl InputAction for NavigateFocus {
+    type Output = Vec2;
+}
+
+fn navigate(
+    navigate: On<Fire<NavigateFocus>>,
+    map: Res<DirectionalNavigationMap>,
+    mut focus: ResMut<InputFocus>,
+    mut visible: ResMut<InputFocusVisible>,
+) {
+    let Some(current) = focus.0 else {
+        return;
+    };
+    let Ok(direction) = Dir2::new(navigate.value) else {
+        return;
+    };
+
+    let octant = CompassOctant::from(direction);
+    if let Some(neighbor) = map.get_neighbor(current, octant) {
+        debug!("navigating focus from `{current}` to `{neighbor}` in `{octant:?}`");
+        focus.set(neighbor);
+        visible.0 = true;
+    }
+}
@@ -47,7 +47,14 @@ Bindings associated with actions via [`BindingOf`] relationship. Similar to [`ac
 we provide the [`bindings!`] macro to spawn related bindings.
 
 By default, input is read from all connected gamepads. You can customize this by adding the [`GamepadDevice`] component to the
-context entity.
+context entity or to individual binding entities. For local multiplayer, [`PlayerSlots`] can assign gamepads to players
+automatically. See the [module documentation](player_slots) for details.
+
+For touch screens, you can bind touches directly or spawn a [`VirtualGamepad`] with on-screen controls
+to reuse your gamepad bindings. See the [module documentation](virtual_gamepad) for details.
+
+Other devices, like MIDI controllers, can be bound via [`Binding::Custom`] after implementing [`InputSource`] for them.
+See the [module documentation](binding::input_source) for details.
 
 ## [Contexts](context)
 
@@ -316,8 +323,11 @@ Each of these is complex to deserve their own section:
 
 # Input and UI
 
-Currently, we don't integrate `bevy_input_focus` directly. But we provide [`ActionSources`] resource
-that could be used to prevents actions from triggering during UI interactions. See its docs for details.
+With the `input_focus` feature, actions can be suppressed while a UI element holds
+`InputFocus`. See the `input_focus` module for details.
+
+We also provide [`ActionSources`] that could be used to prevent actions from triggering during
+other UI interactions, such as hovering buttons. See its docs for details.
 
 # Troubleshooting
 
@@ -347,12 +357,21 @@ pub mod action;
 pub mod binding;
 pub mod condition;
 pub mod context;
+#[cfg(feature = "input_focus")]
+pub mod input_focus;
 pub mod modifier;
+pub mod player_slots;
 pub mod preset;
+pub mod run_condition;
 #[cfg(feature = "state")]
 pub mod state;
+pub mod virtual_gamepad;
 
 pub mod prelude {
+    #[cfg(feature = "window")]
+    pub use super::context::{ContextWindow, window_focus::WindowFocusPolicy};
+    #[cfg(feature = "input_focus")]
+    pub use super::input_focus::{InputFocusPlugin, InputFocusPolicy, NavigateFocus};
     #[cfg(feature = "state")]
     pub use super::state::{ActiveInStates, StateContextAppExt};
     pub use super::{
@@ -360,6 +379,7 @@ pub mod prelude {
         action::{
             Accumulation, Action, ActionOutput, ActionSettings, ActionTime, InputAction,
             TriggerState,
+            dynamic::{DynamicAction, DynamicActionEvent},
             events::*,
             mock::{ActionMock, MockEntityCommandsExt, MockEntityWorldMutExt, MockSpan},
             relationship::{ActionOf, ActionSpawner, ActionSpawnerCommands, Actions},
@@ -367,11 +387,15 @@ pub mod prelude {
         },
         actions,
         binding::{
-            Binding, InputModKeys,
+            Binding, InputModButtons, InputModKeys,
+            gamepad_stick::GamepadStick,
+            input_source::{CustomInput, CustomSources, InputSource, InputSourceAppExt},
+            mod_buttons::ModButtons,
             mod_keys::ModKeys,
             relationship::{
                 BindingOf, BindingSpawner, BindingSpawnerCommands, Bindings, IntoBindingBundle,
             },
+            wheel_notch::{WheelDirection, WheelNotchSettings},
         },
         bindings,
         condition::{
@@ -380,8 +404,13 @@ pub mod prelude {
             release::*, tap::*, toggle::*,
         },
         context::{
-            ActionsQuery, ContextActivity, ContextPriority, GamepadDevice, InputContextAppExt,
+            ActionsQuery, ActivityReason, ContextActivated, ContextActivity, ContextBlocking,
+            ContextDeactivated, ContextPriority, GamepadDevice, InputBlocking, InputContextAppExt,
+            InputDevices,
             input_reader::ActionSources,
+            stack::{
+                ContextPopped, ContextPushed, ContextStack, ContextStackCommandsExt, StackLayer,
+            },
             time::{ContextTime, TimeKind},
         },
         modifier::{
@@ -389,17 +418,27 @@ pub mod prelude {
             exponential_curve::*, fns::InputModifierAppExt, linear_step::*, negate::*, scale::*,
             smooth_nudge::*, swizzle_axis::*,
         },
+        player_slots::{PlayerJoined, PlayerLeft, PlayerSlot, PlayerSlots},
         preset::{WithBundle, axial::*, bidirectional::*, cardinal::*, ordinal::*, spatial::*},
+        run_condition::RunConditionContextAppExt,
+        virtual_gamepad::{VirtualButton, VirtualGamepad, VirtualStick},
     };
     pub use bevy_enhanced_input_macros::InputAction;
 }
 
+#[cfg(feature = "window")]
+use bevy::window::WindowFocused;
 use bevy::{input::InputSystems, prelude::*};
 
+use binding::{
+    BindingChanges,
+    input_source::{CustomBindings, SourceValues},
+    wheel_notch::{self, WheelNotches},
+};
 use condition::fns::ConditionRegistry;
 use context::{
-    ContextRegistry,
-    input_reader::{self, ConsumedInputs, PendingBindings},
+    ContextRegistry, ReplacedActivity,
+    input_reader::{self, ConsumedInputs, KeyLayout, PendingBindings},
 };
 use modifier::fns::ModifierRegistry;
 use prelude::{Press, Release, *};
@@ -414,7 +453,16 @@ impl Plugin for EnhancedInputPlugin {
         app.init_resource::<ContextRegistry>()
             .init_resource::<ConsumedInputs>()
             .init_resource::<PendingBindings>()
+            .init_resource::<KeyLayout>()
+            .init_resource::<ReplacedActivity>()
             .init_resource::<ActionSources>()
+            .init_resource::<CustomSources>()
+            .init_resource::<ContextStack>()
+            .init_resource::<SourceValues>()
+            .init_resource::<CustomBindings>()
+            .init_resource::<BindingChanges>()
+            .init_resource::<WheelNotchSettings>()
+            .init_resource::<WheelNotches>()
             .init_resource::<ConditionRegistry>()
             .init_resource::<ModifierRegistry>()
             .add_input_condition::<BlockBy>()
@@ -438,45 +486,43 @@ impl Plugin for EnhancedInputPlugin {
             .add_input_modifier::<Negate>()
             .add_input_modifier::<Scale>()
             .add_input_modifier::<SmoothNudge>()
-            .add_input_modifier::<SwizzleAxis>()
-            .configure_sets(
-                PreUpdate,
-                (EnhancedInputSystems::Prepare, EnhancedInputSystems::Update)
-                    .chain()
-                    .after(InputSystems),
+            .add_input_modifier::<SwizzleAxis>();
+
+        #[cfg(feature = "window")]
+        app.init_resource::<WindowFocusPolicy>()
+            .add_message::<WindowFocused>();
+
+        app.configure_sets(
+            PreUpdate,
+            (EnhancedInputSystems::Prepare, EnhancedInputSystems::Update)
+                .chain()
+                .after(InputSystems),
+        )
+        .add_systems(
+            PreUpdate,
+            (
+                virtual_gamepad::update,
+                player_slots::update.run_if(resource_exists::<PlayerSlots>),
+                wheel_notch::update,
+                input_reader::update_key_layout,
+                input_reader::update_pending,
             )
-            .add_systems(
-                PreUpdate,
-                input_reader::update_pending.in_set(EnhancedInputSystems::Prepare),
-            );
+                .chain()
+                .in_set(EnhancedInputSystems::Prepare),
+        );
     }
 
     fn finish(&self, app: &mut App) {
-        let context = app
-            .world_mut()
-            .remove_resource::<ContextRegistry>()
-            .expect("contexts registry should be inserted in `build`");
-
-        let conditions = app
-            .world_mut()
-            .remove_resource::<ConditionRegistry>()
-            .expect("conditions registry should be inserted in `build`");
-
-        let modifiers = app
-            .world_mut()
-            .remove_resource::<ModifierRegistry>()
-            .expect("conditions registry should be inserted in `build`");
-
-        for contexts in &*context {
-            contexts.setup(app, &conditions, &modifiers);
-        }
+        context::setup_schedules(app, None);
+        app.world_mut().resource_mut::<ContextRegistry>().finished = true;
     }
 }
 
 /// Label for the system that updates input context instances.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub enum EnhancedInputSystems {
-    /// Updates list of pending inputs to ignore.
+    /// Updates [`VirtualGamepad`]s, [`PlayerSlots`], mouse wheel notches, logical keys of pressed keys,
+    /// values from [`InputSource`]s and the list of pending inputs to ignore.
     ///
     /// Runs in [`PreUpdate`].
     Prepare,
@@ -96,4 +96,9 @@ pub trait InputModifier: Debug {
         time: &ContextTime,
         value: ActionValue,
     ) -> ActionValue;
+
+    /// Resets the internal state, such as accumulated values.
+    ///
+    /// Called when actions are reset without input, for example after losing the window focus.
+    fn reset(&mut self) {}
 }
@@ -83,6 +83,10 @@ impl InputModifier for AccumulateBy {
             value
         }
     }
+
+    fn reset(&mut self) {
+        self.value = Vec3::ZERO;
+    }
 }
 
 #[cfg(test)]
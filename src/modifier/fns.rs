@@ -9,12 +9,17 @@ use bevy::{
     prelude::*,
 };
 
-use crate::prelude::*;
+use crate::{
+    context::{ContextRegistry, setup_schedules},
+    prelude::*,
+};
 
 pub trait InputModifierAppExt {
     /// Registers an input modifier, making it accessible during context evaluation.
     ///
     /// All built-in modifiers are already registered.
+    ///
+    /// Can be called after [`App::finish`]. In this case, the evaluation systems will be rebuilt.
     fn add_input_modifier<M: InputModifier + Component<Mutability = Mutable>>(
         &mut self,
     ) -> &mut Self;
@@ -30,7 +35,13 @@ impl InputModifierAppExt for App {
 
         self.add_observer(register_modifier::<M>)
             .add_observer(unregister_modifier::<M>)
-            .register_required_components::<M, ModifierFns>()
+            .register_required_components::<M, ModifierFns>();
+
+        if self.world().resource::<ContextRegistry>().finished {
+            setup_schedules(self, None);
+        }
+
+        self
     }
 }
 
@@ -57,8 +68,6 @@ fn unregister_modifier<M: InputModifier + Component<Mutability = Mutable>>(
 /// IDs of all registered input modifiers.
 ///
 /// Used to dynamically register access for [`FilteredEntityMut`].
-///
-/// Exists only during the plugin initialization.
 #[derive(Resource, Deref, Default)]
 pub(crate) struct ModifierRegistry(Vec<ComponentId>);
 
@@ -68,7 +77,7 @@ pub(crate) struct ModifierRegistry(Vec<ComponentId>);
 /// we dynamically get them as the trait from [`FilteredEntityMut`].
 ///
 /// Updated automatically using triggers.
-#[derive(Component, Deref, Default)]
+#[derive(Component, Deref, Default, Clone)]
 pub(crate) struct ModifierFns(Vec<GetModifierFn>);
 
 type GetModifierFn = for<'a> fn(&'a mut FilteredEntityMut) -> &'a mut dyn InputModifier;
@@ -82,6 +82,10 @@ impl InputModifier for LinearStep {
 
         ActionValue::Axis3D(self.current_value).convert(value.dim())
     }
+
+    fn reset(&mut self) {
+        self.current_value = Vec3::ZERO;
+    }
 }
 
 #[cfg(test)]
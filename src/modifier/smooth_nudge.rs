@@ -75,6 +75,10 @@ impl InputModifier for SmoothNudge {
 
         ActionValue::Axis3D(self.current_value).convert(value.dim())
     }
+
+    fn reset(&mut self) {
+        self.current_value = Vec3::ZERO;
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,325 @@
+/*!
+Gamepad assignment for local multiplayer.
+
+[`PlayerSlots`] assigns each gamepad to a numbered slot. Gamepads join by pressing
+[`PlayerSlots::join_button`] or automatically on connection, triggering [`PlayerJoined`].
+Add [`PlayerSlot`] to context entities to keep their [`GamepadDevice`] in sync with the slot.
+Join and leave events are triggered on these entities, so they can be observed per player.
+
+When a gamepad disconnects, its slot stays occupied and [`GamepadDevice::None`] is assigned until
+a gamepad with the same name, vendor and product IDs joins again, even if it's a new entity.
+Gamepads of the same model can't be told apart, so if [`PlayerSlots::join_button`] is set,
+the reconnected gamepad needs to press it to reclaim the slot. This way, a gamepad of the same model
+that connects in the meantime doesn't take the slot without any input. If multiple disconnected
+slots match, the first one is reclaimed.
+
+[`VirtualGamepad`]s are treated like other gamepads and can join as well.
+Spawn them with a unique [`Name`] to let them reclaim their slots after respawning.
+
+The subsystem is disabled until [`PlayerSlots`] is inserted.
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .add_input_context::<Player>()
+    .insert_resource(PlayerSlots::new(2))
+    .add_observer(spawn_player);
+
+fn spawn_player(joined: On<PlayerJoined>, mut commands: Commands) {
+    commands.spawn((
+        Player,
+        PlayerSlot(joined.slot),
+        actions!(Player[
+            (Action::<Jump>::new(), bindings![GamepadButton::South]),
+        ]),
+    ));
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+```
+*/
+
+use alloc::{string::String, vec::Vec};
+
+use bevy::prelude::*;
+use log::debug;
+
+use crate::prelude::*;
+
+/// Gamepads assigned to player slots.
+///
+/// Insert this resource to enable automatic gamepad assignment.
+/// Updated during [`EnhancedInputSystems::Prepare`].
+#[derive(Resource, Debug)]
+pub struct PlayerSlots {
+    /// Maximum number of slots.
+    ///
+    /// By default set to 4.
+    pub max_players: usize,
+
+    /// Button that an unassigned gamepad needs to press to join or reclaim its slot after reconnection.
+    ///
+    /// If [`None`], gamepads join on connection.
+    ///
+    /// By default set to [`GamepadButton::Start`].
+    pub join_button: Option<GamepadButton>,
+
+    /// Button that frees the slot of the gamepad that pressed it.
+    ///
+    /// By default set to [`None`], so players can't leave by themselves.
+    pub leave_button: Option<GamepadButton>,
+
+    slots: Vec<Option<Slot>>,
+}
+
+impl PlayerSlots {
+    /// Creates a new instance with the given number of slots.
+    #[must_use]
+    pub fn new(max_players: usize) -> Self {
+        Self {
+            max_players,
+            ..Default::default()
+        }
+    }
+
+    /// Sets [`Self::join_button`].
+    #[must_use]
+    pub fn with_join_button(mut self, join_button: Option<GamepadButton>) -> Self {
+        self.join_button = join_button;
+        self
+    }
+
+    /// Sets [`Self::leave_button`].
+    #[must_use]
+    pub fn with_leave_button(mut self, leave_button: Option<GamepadButton>) -> Self {
+        self.leave_button = leave_button;
+        self
+    }
+
+    /// Returns the connected gamepad assigned to the slot.
+    #[must_use]
+    pub fn gamepad(&self, slot: usize) -> Option<Entity> {
+        self.slots
+            .get(slot)
+            .and_then(|slot| slot.as_ref())
+            .and_then(|slot| slot.gamepad)
+    }
+
+    /// Returns `true` if the slot has a player, even if their gamepad is disconnected.
+    #[must_use]
+    pub fn is_occupied(&self, slot: usize) -> bool {
+        self.slots.get(slot).is_some_and(Option::is_some)
+    }
+
+    /// Returns the slot to which the gamepad is assigned.
+    #[must_use]
+    pub fn slot_of(&self, gamepad: Entity) -> Option<usize> {
+        self.slots.iter().position(|slot| {
+            slot.as_ref()
+                .is_some_and(|slot| slot.gamepad == Some(gamepad))
+        })
+    }
+
+    /// Frees the slot without triggering [`PlayerLeft`].
+    ///
+    /// Returns `true` if the slot was occupied.
+    pub fn clear(&mut self, slot: usize) -> bool {
+        self.slots.get_mut(slot).and_then(Option::take).is_some()
+    }
+
+    fn free_slot(&self) -> Option<usize> {
+        (0..self.max_players).find(|&slot| !self.is_occupied(slot))
+    }
+
+    fn assign(&mut self, slot: usize, gamepad: Entity, identity: GamepadIdentity) {
+        if self.slots.len() <= slot {
+            self.slots.resize_with(slot + 1, Default::default);
+        }
+        self.slots[slot] = Some(Slot {
+            gamepad: Some(gamepad),
+            identity,
+        });
+    }
+}
+
+impl Default for PlayerSlots {
+    fn default() -> Self {
+        Self {
+            max_players: 4,
+            join_button: Some(GamepadButton::Start),
+            leave_button: None,
+            slots: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Slot {
+    /// Assigned gamepad, or [`None`] while it's disconnected.
+    gamepad: Option<Entity>,
+
+    /// Used to find the gamepad after reconnection.
+    identity: GamepadIdentity,
+}
+
+/// Information to recognize a gamepad after reconnection.
+#[derive(Debug, PartialEq, Eq)]
+struct GamepadIdentity {
+    name: String,
+    vendor_id: Option<u16>,
+    product_id: Option<u16>,
+}
+
+impl GamepadIdentity {
+    fn new(gamepad: &Gamepad, name: Option<&Name>) -> Self {
+        Self {
+            name: name.map(|name| name.as_str().into()).unwrap_or_default(),
+            vendor_id: gamepad.vendor_id(),
+            product_id: gamepad.product_id(),
+        }
+    }
+}
+
+/// Assigns [`GamepadDevice`] from the slot in [`PlayerSlots`] for all input contexts on this entity.
+#[derive(Component, Debug, Hash, PartialEq, Eq, Clone, Copy, Deref)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Hash, PartialEq)
+)]
+#[require(GamepadDevice = GamepadDevice::None)]
+pub struct PlayerSlot(pub usize);
+
+/// Triggered when a gamepad is assigned to a free slot in [`PlayerSlots`].
+///
+/// Triggered on each entity with the matching [`PlayerSlot`], or on the gamepad entity if there are none.
+/// Not triggered when a gamepad reconnects to its slot.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct PlayerJoined {
+    /// Entity with the matching [`PlayerSlot`] or the gamepad.
+    pub entity: Entity,
+
+    /// Index of the slot.
+    pub slot: usize,
+
+    /// Assigned gamepad.
+    pub gamepad: Entity,
+}
+
+/// Triggered when a gamepad frees its slot in [`PlayerSlots`] by pressing [`PlayerSlots::leave_button`].
+///
+/// Triggered on each entity with the matching [`PlayerSlot`], or on the gamepad entity if there are none.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct PlayerLeft {
+    /// Entity with the matching [`PlayerSlot`] or the gamepad.
+    pub entity: Entity,
+
+    /// Index of the freed slot.
+    pub slot: usize,
+
+    /// Gamepad that was assigned to the slot.
+    pub gamepad: Entity,
+}
+
+pub(crate) fn update(
+    mut commands: Commands,
+    mut slots: ResMut<PlayerSlots>,
+    gamepads: Query<(Entity, &Gamepad, Option<&Name>)>,
+    mut players: Query<(Entity, &PlayerSlot, &mut GamepadDevice)>,
+) {
+    for (index, slot) in slots.slots.iter_mut().enumerate() {
+        if let Some(slot) = slot
+            && let Some(gamepad) = slot.gamepad
+            && !gamepads.contains(gamepad)
+        {
+            debug!("disconnecting `{gamepad}` from slot {index}");
+            slot.gamepad = None;
+        }
+    }
+
+    for (entity, gamepad, name) in &gamepads {
+        if let Some(index) = slots.slot_of(entity) {
+            if slots
+                .leave_button
+                .is_some_and(|button| gamepad.just_pressed(button))
+            {
+                debug!("freeing slot {index} from `{entity}`");
+                slots.clear(index);
+                for target in slot_targets(&players, index, entity) {
+                    commands.trigger(PlayerLeft {
+                        entity: target,
+                        slot: index,
+                        gamepad: entity,
+                    });
+                }
+            }
+            continue;
+        }
+
+        if !slots
+            .join_button
+            .is_none_or(|button| gamepad.just_pressed(button))
+        {
+            continue;
+        }
+
+        let identity = GamepadIdentity::new(gamepad, name);
+        let reconnected = slots
+            .slots
+            .iter_mut()
+            .enumerate()
+            .find_map(|(index, slot)| {
+                slot.as_mut()
+                    .filter(|slot| slot.gamepad.is_none() && slot.identity == identity)
+                    .map(|slot| (index, slot))
+            });
+        if let Some((index, slot)) = reconnected {
+            debug!("reconnecting `{entity}` to slot {index}");
+            slot.gamepad = Some(entity);
+            continue;
+        }
+
+        if let Some(index) = slots.free_slot() {
+            debug!("assigning `{entity}` to slot {index}");
+            slots.assign(index, entity, identity);
+            for target in slot_targets(&players, index, entity) {
+                commands.trigger(PlayerJoined {
+                    entity: target,
+                    slot: index,
+                    gamepad: entity,
+                });
+            }
+        }
+    }
+
+    for (_, &slot, mut device) in &mut players {
+        device.set_if_neq(slots.gamepad(*slot).into());
+    }
+}
+
+/// Returns entities with the slot or the gamepad if there are none.
+fn slot_targets(
+    players: &Query<(Entity, &PlayerSlot, &mut GamepadDevice)>,
+    slot: usize,
+    gamepad: Entity,
+) -> Vec<Entity> {
+    let mut targets: Vec<_> = players
+        .iter()
+        .filter(|&(_, &player_slot, _)| *player_slot == slot)
+        .map(|(entity, ..)| entity)
+        .collect();
+    if targets.is_empty() {
+        targets.push(gamepad);
+    }
+    targets
+}
@@ -3,6 +3,8 @@ use bevy::{ecs::spawn::SpawnableList, prelude::*, ptr::MovingPtr};
 use crate::prelude::*;
 
 /// A preset to map 2 axes as 2-dimensional input.
+///
+/// To read both axes of a gamepad stick as a single binding, use [`Binding::GamepadStick`] instead.
 #[derive(Debug, Clone, Copy)]
 pub struct Axial<X, Y> {
     pub x: X,
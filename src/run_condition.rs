@@ -0,0 +1,81 @@
+/*!
+Run condition integration for activating input contexts based on arbitrary conditions.
+
+Similar to `ActiveInStates` from the `state` module, but evaluates a Bevy
+[`SystemCondition`] every frame, such as [`resource_exists`] or a custom system returning [`bool`].
+This covers cases like "in water" or "cursor grabbed" that aren't represented by [`States`].
+
+[`ContextActivity`] is inserted only when the condition result differs from the current activity.
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .init_resource::<InWater>()
+    .add_input_context::<Swimming>()
+    .sync_context_to_condition::<Swimming, _>(|in_water: Res<InWater>| **in_water)
+    .finish();
+
+#[derive(Resource, Default, Deref)]
+struct InWater(bool);
+
+#[derive(Component)]
+struct Swimming;
+```
+*/
+
+use bevy::prelude::*;
+use log::debug;
+
+use crate::{context::set_context_activity, prelude::*};
+
+/// Extension trait for synchronizing input contexts with run conditions.
+pub trait RunConditionContextAppExt {
+    /// Registers automatic synchronization between context `C` and the run condition.
+    ///
+    /// The condition is evaluated every frame during [`EnhancedInputSystems::Prepare`],
+    /// and [`ContextActivity<C>`] of all entities with `C` is updated to match its result.
+    ///
+    /// Should be called only once per context, since multiple conditions will override each other.
+    fn sync_context_to_condition<C: Component, M>(
+        &mut self,
+        condition: impl SystemCondition<M>,
+    ) -> &mut Self;
+}
+
+impl RunConditionContextAppExt for App {
+    fn sync_context_to_condition<C: Component, M>(
+        &mut self,
+        condition: impl SystemCondition<M>,
+    ) -> &mut Self {
+        debug!("registering condition sync for `{}`", ShortName::of::<C>());
+
+        self.add_systems(
+            PreUpdate,
+            condition
+                .pipe(sync_condition_contexts::<C>)
+                .in_set(EnhancedInputSystems::Prepare),
+        )
+    }
+}
+
+fn sync_condition_contexts<C: Component>(
+    In(active): In<bool>,
+    mut commands: Commands,
+    contexts: Query<Entity, With<C>>,
+    activity: Query<&ContextActivity<C>>,
+) {
+    for entity in &contexts {
+        set_context_activity(
+            &mut commands,
+            &activity,
+            entity,
+            active,
+            ActivityReason::Condition,
+        );
+    }
+}
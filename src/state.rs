@@ -55,7 +55,7 @@ use bevy::{
 use log::debug;
 use smallvec::SmallVec;
 
-use crate::prelude::*;
+use crate::{context::set_context_activity, prelude::*};
 
 /// Extension trait for synchronizing input contexts with [`States`].
 pub trait StateContextAppExt {
@@ -98,6 +98,7 @@ fn sync_on_insert<C: Component, S: States>(
         &activity,
         insert.entity,
         active_in.matches(current_state.get()),
+        ActivityReason::State,
     );
 }
 
@@ -114,37 +115,29 @@ fn sync_state_contexts<C: Component, S: States>(
     match &transition.entered {
         Some(entered) => {
             for (entity, active_in) in &contexts {
-                set_context_activity(&mut commands, &activity, entity, active_in.matches(entered));
+                set_context_activity(
+                    &mut commands,
+                    &activity,
+                    entity,
+                    active_in.matches(entered),
+                    ActivityReason::State,
+                );
             }
         }
         None => {
             for (entity, _) in &contexts {
-                set_context_activity(&mut commands, &activity, entity, false);
+                set_context_activity(
+                    &mut commands,
+                    &activity,
+                    entity,
+                    false,
+                    ActivityReason::State,
+                );
             }
         }
     }
 }
 
-fn set_context_activity<C: Component>(
-    commands: &mut Commands,
-    activity: &Query<&ContextActivity<C>>,
-    entity: Entity,
-    active: bool,
-) {
-    if let Ok(current) = activity.get(entity)
-        && **current == active
-    {
-        return;
-    }
-    debug!(
-        "setting `{}` on `{entity}` to `{active}`",
-        ShortName::of::<C>(),
-    );
-    commands
-        .entity(entity)
-        .insert(ContextActivity::<C>::new(active));
-}
-
 /// Inserts [`ContextActivity::<C>::ACTIVE`] when the state matches one of the
 /// specified values, and [`ContextActivity::<C>::INACTIVE`] otherwise.
 #[derive(Component)]
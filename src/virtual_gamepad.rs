@@ -0,0 +1,195 @@
+/*!
+On-screen controls that drive a [`Gamepad`] from touches.
+
+Instead of introducing separate bindings for on-screen controls, a [`VirtualGamepad`]
+writes touch input into a regular [`Gamepad`] component. This way, mobile players
+reuse the same contexts and bindings as players with a physical gamepad.
+
+# Example
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Movement;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+let mut world = World::new();
+let gamepad = world
+    .spawn(VirtualGamepad {
+        left_stick: Some(VirtualStick::new(Vec2::new(150.0, 600.0), 100.0)),
+        buttons: vec![VirtualButton {
+            button: GamepadButton::South,
+            area: Rect::new(1100.0, 550.0, 1200.0, 650.0),
+        }],
+        ..Default::default()
+    })
+    .id();
+
+world.spawn((
+    Player,
+    GamepadDevice::Single(gamepad),
+    actions!(Player[
+        (
+            Action::<Movement>::new(),
+            DeadZone::default(),
+            Bindings::spawn(Axial::left_stick()),
+        ),
+        (
+            Action::<Jump>::new(),
+            bindings![GamepadButton::South],
+        ),
+    ]),
+));
+```
+*/
+
+use alloc::vec::Vec;
+
+use bevy::prelude::*;
+
+/// Gamepad driven by on-screen controls.
+///
+/// Requires [`Gamepad`], so all gamepad bindings can read from it. It will be matched by
+/// [`GamepadDevice::Any`](crate::prelude::GamepadDevice::Any), or you can assign it to a context
+/// explicitly using [`GamepadDevice::Single`](crate::prelude::GamepadDevice::Single).
+///
+/// Updated from [`Touches`] during [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare).
+#[derive(Component, Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+#[require(Gamepad)]
+pub struct VirtualGamepad {
+    /// Drives [`GamepadAxis::LeftStickX`] and [`GamepadAxis::LeftStickY`].
+    pub left_stick: Option<VirtualStick>,
+
+    /// Drives [`GamepadAxis::RightStickX`] and [`GamepadAxis::RightStickY`].
+    pub right_stick: Option<VirtualStick>,
+
+    /// On-screen buttons.
+    pub buttons: Vec<VirtualButton>,
+}
+
+/// On-screen joystick for [`VirtualGamepad`].
+///
+/// Captures a touch that starts within [`Self::radius`] from [`Self::center`] and follows
+/// it until release, even if it leaves the circle.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug))]
+pub struct VirtualStick {
+    /// Center of the stick in logical pixels.
+    pub center: Vec2,
+
+    /// Distance from the center in logical pixels at which the stick is fully tilted.
+    pub radius: f32,
+
+    /// Touch that currently drives the stick.
+    touch: Option<u64>,
+}
+
+impl VirtualStick {
+    /// Creates a new instance with the given center and radius in logical pixels.
+    #[must_use]
+    pub const fn new(center: Vec2, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            touch: None,
+        }
+    }
+
+    /// Updates the captured touch and returns the stick value.
+    ///
+    /// Unlike screen coordinates, the Y axis of the returned value points up,
+    /// matching gamepad sticks.
+    fn update(&mut self, touches: &Touches) -> Vec2 {
+        if let Some(id) = self.touch
+            && touches.get_pressed(id).is_none()
+        {
+            self.touch = None;
+        }
+
+        if self.touch.is_none() {
+            self.touch = touches
+                .iter_just_pressed()
+                .find(|touch| touch.position().distance(self.center) <= self.radius)
+                .map(|touch| touch.id());
+        }
+
+        let Some(touch) = self.touch.and_then(|id| touches.get_pressed(id)) else {
+            return Vec2::ZERO;
+        };
+
+        let offset = (touch.position() - self.center) / self.radius;
+        Vec2::new(offset.x, -offset.y).clamp_length_max(1.0)
+    }
+}
+
+/// On-screen button for [`VirtualGamepad`].
+///
+/// Pressed while any touch is inside [`Self::area`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug))]
+pub struct VirtualButton {
+    /// Gamepad button to press.
+    pub button: GamepadButton,
+
+    /// Screen-space rectangle in logical pixels.
+    pub area: Rect,
+}
+
+pub(crate) fn update(
+    touches: Option<Res<Touches>>,
+    mut gamepads: Query<(&mut VirtualGamepad, &mut Gamepad)>,
+) {
+    let Some(touches) = touches else {
+        return;
+    };
+
+    for (mut virtual_gamepad, mut gamepad) in &mut gamepads {
+        let virtual_gamepad = &mut *virtual_gamepad;
+        for (stick, x, y) in [
+            (
+                &mut virtual_gamepad.left_stick,
+                GamepadAxis::LeftStickX,
+                GamepadAxis::LeftStickY,
+            ),
+            (
+                &mut virtual_gamepad.right_stick,
+                GamepadAxis::RightStickX,
+                GamepadAxis::RightStickY,
+            ),
+        ] {
+            if let Some(stick) = stick {
+                let value = stick.update(&touches);
+                gamepad.analog_mut().set(x, value.x);
+                gamepad.analog_mut().set(y, value.y);
+            }
+        }
+
+        for button in &virtual_gamepad.buttons {
+            let pressed = touches
+                .iter()
+                .any(|touch| button.area.contains(touch.position()));
+
+            if pressed {
+                gamepad.digital_mut().press(button.button);
+                gamepad.analog_mut().set(button.button, 1.0);
+            } else {
+                gamepad.digital_mut().release(button.button);
+                gamepad.analog_mut().set(button.button, 0.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn same_frame() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app.world_mut().spawn(TestContext).id();
+    let source = app.world_mut().spawn_empty().id();
+
+    // Spawn the derived action first to ensure that the evaluation order doesn't depend on it.
+    app.world_mut().spawn((
+        ActionOf::<TestContext>::new(context),
+        Action::<Derived>::new(),
+        bindings![Binding::Action(source)],
+    ));
+    app.world_mut().entity_mut(source).insert((
+        ActionOf::<TestContext>::new(context),
+        Action::<Source>::new(),
+        bindings![Source::KEY],
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Source::KEY);
+
+    app.update();
+
+    let mut derived = app.world_mut().query::<&Action<Derived>>();
+    let derived_value = *derived.single(app.world()).unwrap();
+    assert!(*derived_value, "should read the value from the same frame");
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Source::KEY);
+
+    app.update();
+
+    let derived_value = *derived.single(app.world()).unwrap();
+    assert!(!*derived_value);
+}
+
+#[test]
+fn rebind() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app.world_mut().spawn(TestContext).id();
+    let derived = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Derived>::new(),
+        ))
+        .id();
+    let source = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Source>::new(),
+            bindings![Source::KEY],
+        ))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .spawn((BindingOf(derived), Binding::Action(source)));
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Source::KEY);
+
+    app.update();
+
+    let derived_value = *app.world().get::<Action<Derived>>(derived).unwrap();
+    assert!(
+        *derived_value,
+        "order should be updated after adding a binding"
+    );
+}
+
+#[test]
+fn cycle() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app.world_mut().spawn(TestContext).id();
+    let source = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Source>::new(),
+            bindings![Source::KEY],
+        ))
+        .id();
+    let derived = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Derived>::new(),
+            bindings![Binding::Action(source)],
+        ))
+        .id();
+    app.world_mut()
+        .spawn((BindingOf(source), Binding::Action(derived)));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Source::KEY);
+
+    app.update();
+    app.update();
+
+    let source_value = *app.world().get::<Action<Source>>(source).unwrap();
+    assert!(*source_value);
+
+    let derived_value = *app.world().get::<Action<Derived>>(derived).unwrap();
+    assert!(*derived_value);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Source;
+
+impl Source {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Derived;
@@ -0,0 +1,93 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn per_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .finish();
+
+    let hovered = app
+        .world_mut()
+        .spawn((
+            Player,
+            ActionSources {
+                mouse_buttons: false,
+                ..Default::default()
+            },
+            actions!(Player[(Action::<Attack>::new(), bindings![Attack::BUTTON])]),
+        ))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((
+            Player,
+            actions!(Player[(Action::<Attack>::new(), bindings![Attack::BUTTON])]),
+        ))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(Attack::BUTTON);
+
+    app.update();
+
+    assert_eq!(action_state(&mut app, hovered), TriggerState::None);
+    assert_eq!(
+        action_state(&mut app, other),
+        TriggerState::Fired,
+        "component should affect only its context"
+    );
+}
+
+#[test]
+fn overrides_resource() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .insert_resource(ActionSources {
+            mouse_buttons: false,
+            ..Default::default()
+        })
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            Player,
+            ActionSources::default(),
+            actions!(Player[(Action::<Attack>::new(), bindings![Attack::BUTTON])]),
+        ))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(Attack::BUTTON);
+
+    app.update();
+
+    assert_eq!(action_state(&mut app, context), TriggerState::Fired);
+}
+
+fn action_state(app: &mut App, context: Entity) -> TriggerState {
+    let actions = app.world().get::<Actions<Player>>(context).unwrap();
+    let action = *actions.iter().next().as_ref().unwrap();
+    *app.world().get::<TriggerState>(action).unwrap()
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Attack;
+
+impl Attack {
+    const BUTTON: MouseButton = MouseButton::Left;
+}
@@ -0,0 +1,99 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn manual() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Changes>()
+        .add_input_context::<Test>()
+        .add_observer(activated)
+        .add_observer(deactivated)
+        .finish();
+
+    let context = app.world_mut().spawn(Test).id();
+    assert_eq!(
+        **app.world().resource::<Changes>(),
+        [(true, ActivityReason::Added)]
+    );
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(ContextActivity::<Test>::INACTIVE);
+    app.world_mut()
+        .entity_mut(context)
+        .insert(ContextActivity::<Test>::INACTIVE);
+    app.world_mut()
+        .entity_mut(context)
+        .insert(ContextActivity::<Test>::ACTIVE);
+
+    assert_eq!(
+        **app.world().resource::<Changes>(),
+        [
+            (true, ActivityReason::Added),
+            (false, ActivityReason::Manual),
+            (true, ActivityReason::Manual),
+        ],
+        "inserting the same value shouldn't trigger events"
+    );
+}
+
+#[test]
+fn removal() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Changes>()
+        .add_input_context::<Test>()
+        .add_observer(activated)
+        .add_observer(deactivated)
+        .finish();
+
+    let context = app.world_mut().spawn(Test).id();
+    app.world_mut().resource_mut::<Changes>().clear();
+
+    app.world_mut().entity_mut(context).despawn();
+
+    assert_eq!(
+        **app.world().resource::<Changes>(),
+        [(false, ActivityReason::Removed)]
+    );
+}
+
+#[test]
+fn stack() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Changes>()
+        .add_input_context::<Test>()
+        .add_observer(activated)
+        .add_observer(deactivated)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((Test, ContextActivity::<Test>::INACTIVE))
+        .id();
+
+    app.world_mut().commands().push_context::<Test>(context);
+    app.world_mut().flush();
+
+    assert_eq!(
+        **app.world().resource::<Changes>(),
+        [(true, ActivityReason::Stack)]
+    );
+}
+
+fn activated(activated: On<ContextActivated<Test>>, mut changes: ResMut<Changes>) {
+    changes.push((true, activated.reason));
+}
+
+fn deactivated(deactivated: On<ContextDeactivated<Test>>, mut changes: ResMut<Changes>) {
+    changes.push((false, deactivated.reason));
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Changes(Vec<(bool, ActivityReason)>);
+
+#[derive(Component)]
+struct Test;
@@ -294,6 +294,76 @@ fn modifiers() {
     assert_eq!(second_state, TriggerState::Fired);
 }
 
+#[test]
+fn mod_buttons() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<First>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![BUTTON],
+            ),
+            (
+                Action::<Second>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![BUTTON.with_mod_buttons(MOD_BUTTON.into())],
+            )
+        ]),
+    ));
+
+    app.update();
+
+    let mut gamepad_component = app.world_mut().get_mut::<Gamepad>(gamepad).unwrap();
+    gamepad_component.digital_mut().press(BUTTON);
+    gamepad_component.analog_mut().set(BUTTON, 1.0);
+
+    app.update();
+
+    let mut first = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<First>>>();
+
+    let first_state = *first.single(app.world()).unwrap();
+    assert_eq!(first_state, TriggerState::Fired);
+
+    let mut second = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Second>>>();
+
+    let second_state = *second.single(app.world()).unwrap();
+    assert_eq!(second_state, TriggerState::None);
+
+    let mut gamepad_component = app.world_mut().get_mut::<Gamepad>(gamepad).unwrap();
+    gamepad_component.digital_mut().press(MOD_BUTTON);
+    gamepad_component.analog_mut().set(MOD_BUTTON, 1.0);
+
+    app.update();
+
+    let first_state = *first.single(app.world()).unwrap();
+    assert_eq!(
+        first_state,
+        TriggerState::None,
+        "action with modifier should be evaluated first and consume the input"
+    );
+
+    let second_state = *second.single(app.world()).unwrap();
+    assert_eq!(second_state, TriggerState::Fired);
+}
+
 #[derive(Component, Clone, Copy)]
 struct TestContext;
 
@@ -301,6 +371,10 @@ struct TestContext;
 const KEY: KeyCode = KeyCode::KeyA;
 const MOD: ModKeys = ModKeys::CONTROL;
 
+/// Gamepad buttons used by all actions.
+const BUTTON: GamepadButton = GamepadButton::South;
+const MOD_BUTTON: GamepadButton = GamepadButton::LeftTrigger;
+
 #[derive(InputAction)]
 #[action_output(bool)]
 struct First;
@@ -0,0 +1,203 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn all() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Dialog>()
+        .add_input_context::<Gameplay>()
+        .finish();
+
+    app.world_mut().spawn((
+        Dialog,
+        ContextPriority::<Dialog>::new(1),
+        ContextBlocking::<Dialog>::ALL,
+        actions!(Dialog[(Action::<Confirm>::new(), bindings![Confirm::KEY])]),
+    ));
+    app.world_mut().spawn((
+        Gameplay,
+        actions!(Gameplay[
+            (Action::<Jump>::new(), bindings![Jump::KEY]),
+            (Action::<Attack>::new(), bindings![Attack::BUTTON]),
+        ]),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(Attack::BUTTON);
+
+    app.update();
+
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+    assert_eq!(state::<Attack>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn bound() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Dialog>()
+        .add_input_context::<Gameplay>()
+        .finish();
+
+    app.world_mut().spawn((
+        Dialog,
+        ContextPriority::<Dialog>::new(1),
+        ContextBlocking::<Dialog>::BOUND,
+        actions!(
+            Dialog[(
+                Action::<Confirm>::new(),
+                Hold::new(1.0),
+                bindings![Confirm::KEY]
+            )]
+        ),
+    ));
+    app.world_mut().spawn((
+        Gameplay,
+        actions!(Gameplay[
+            (Action::<Interact>::new(), bindings![Confirm::KEY]),
+            (Action::<Jump>::new(), bindings![Jump::KEY]),
+        ]),
+    ));
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(Confirm::KEY);
+    keys.press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(
+        state::<Interact>(&mut app),
+        TriggerState::None,
+        "bound input should be blocked even if the action didn't fire"
+    );
+    assert_eq!(state::<Jump>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn devices() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Dialog>()
+        .add_input_context::<Gameplay>()
+        .finish();
+
+    app.world_mut().spawn((
+        Dialog,
+        ContextPriority::<Dialog>::new(1),
+        ContextBlocking::<Dialog>::devices(InputDevices::KEYBOARD),
+    ));
+    app.world_mut().spawn((
+        Gameplay,
+        actions!(Gameplay[
+            (Action::<Jump>::new(), bindings![Jump::KEY]),
+            (Action::<Attack>::new(), bindings![Attack::BUTTON]),
+            (
+                Action::<Interact>::new(),
+                bindings![Attack::BUTTON.with_mod_keys(ModKeys::SHIFT)]
+            ),
+        ]),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ShiftLeft);
+    app.world_mut()
+        .resource_mut::<ButtonInput<MouseButton>>()
+        .press(Attack::BUTTON);
+
+    app.update();
+
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+    assert_eq!(state::<Attack>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Interact>(&mut app),
+        TriggerState::None,
+        "keyboard modifiers should be blocked"
+    );
+}
+
+#[test]
+fn inactive() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Dialog>()
+        .add_input_context::<Gameplay>()
+        .finish();
+
+    app.world_mut().spawn((
+        Dialog,
+        ContextPriority::<Dialog>::new(1),
+        ContextActivity::<Dialog>::INACTIVE,
+        ContextBlocking::<Dialog>::ALL,
+    ));
+    app.world_mut().spawn((
+        Gameplay,
+        actions!(Gameplay[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(state::<Jump>(&mut app), TriggerState::Fired);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>();
+    *actions.single(app.world()).unwrap()
+}
+
+#[derive(Component)]
+struct Dialog;
+
+#[derive(Component)]
+struct Gameplay;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Confirm;
+
+impl Confirm {
+    const KEY: KeyCode = KeyCode::Enter;
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Interact;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+impl Jump {
+    const KEY: KeyCode = KeyCode::Space;
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Attack;
+
+impl Attack {
+    const BUTTON: MouseButton = MouseButton::Left;
+}
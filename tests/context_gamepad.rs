@@ -80,6 +80,121 @@ fn by_id() {
     assert_eq!(state, TriggerState::None);
 }
 
+#[test]
+fn binding_override() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        GamepadDevice::Single(gamepad_entity1),
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                bindings![(Test::BUTTON, GamepadDevice::Single(gamepad_entity2))]
+            )]
+        ),
+    ));
+
+    app.update();
+
+    let mut gamepad1 = app.world_mut().get_mut::<Gamepad>(gamepad_entity1).unwrap();
+    gamepad1.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&TriggerState>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::None,
+        "binding device should override the context device"
+    );
+
+    let mut gamepad1 = app.world_mut().get_mut::<Gamepad>(gamepad_entity1).unwrap();
+    gamepad1.analog_mut().set(Test::BUTTON, 0.0);
+
+    let mut gamepad2 = app.world_mut().get_mut::<Gamepad>(gamepad_entity2).unwrap();
+    gamepad2.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+}
+
+#[test]
+fn binding_consume() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<Test>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![(Test::BUTTON, GamepadDevice::Single(gamepad_entity1))],
+            ),
+            (
+                Action::<Other>::new(),
+                bindings![
+                    (Test::BUTTON, GamepadDevice::Single(gamepad_entity1)),
+                    (Test::BUTTON, GamepadDevice::Single(gamepad_entity2)),
+                ],
+            ),
+        ]),
+    ));
+
+    app.update();
+
+    let mut gamepad1 = app.world_mut().get_mut::<Gamepad>(gamepad_entity1).unwrap();
+    gamepad1.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let mut test = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Test>>>();
+    let state = *test.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+
+    let mut other = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Other>>>();
+    let state = *other.single(app.world()).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::None,
+        "input from the first gamepad should be consumed"
+    );
+
+    let mut gamepad2 = app.world_mut().get_mut::<Gamepad>(gamepad_entity2).unwrap();
+    gamepad2.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let state = *other.single(app.world()).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::Fired,
+        "input from the second gamepad shouldn't be consumed"
+    );
+}
+
 #[derive(Component)]
 struct TestContext;
 
@@ -90,3 +205,7 @@ struct Test;
 impl Test {
     const BUTTON: GamepadButton = GamepadButton::South;
 }
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Other;
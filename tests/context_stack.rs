@@ -0,0 +1,224 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn push_pop() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Gameplay>()
+        .add_input_context::<Menu>()
+        .finish();
+
+    let gameplay = app
+        .world_mut()
+        .spawn((
+            Gameplay,
+            actions!(Gameplay[(Action::<Test>::new(), bindings![Test::KEY])]),
+        ))
+        .id();
+    let menu = app
+        .world_mut()
+        .spawn((
+            Menu,
+            ContextPriority::<Menu>::new(5),
+            ContextActivity::<Menu>::INACTIVE,
+            actions!(
+                Menu[(
+                    Action::<Test>::new(),
+                    ActionSettings {
+                        consume_input: true,
+                        ..Default::default()
+                    },
+                    bindings![Test::KEY]
+                )]
+            ),
+        ))
+        .id();
+
+    app.world_mut()
+        .commands()
+        .push_context::<Gameplay>(gameplay)
+        .push_context::<Menu>(menu);
+    app.world_mut().flush();
+
+    let stack = app.world().resource::<ContextStack>();
+    assert_eq!(stack.len(), 2);
+    assert!(stack.top().unwrap().is::<Menu>());
+
+    let menu_priority = **app.world().get::<ContextPriority<Menu>>(menu).unwrap();
+    let gameplay_priority = **app
+        .world()
+        .get::<ContextPriority<Gameplay>>(gameplay)
+        .unwrap();
+    assert!(menu_priority > gameplay_priority);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let gameplay_action = action_state::<Gameplay>(&mut app, gameplay);
+    let menu_action = action_state::<Menu>(&mut app, menu);
+    assert_eq!(menu_action, TriggerState::Fired);
+    assert_eq!(
+        gameplay_action,
+        TriggerState::None,
+        "input should be consumed by the top layer"
+    );
+
+    app.world_mut().commands().pop_context();
+    app.world_mut().flush();
+
+    assert_eq!(app.world().resource::<ContextStack>().len(), 1);
+    assert!(
+        !**app.world().get::<ContextActivity<Menu>>(menu).unwrap(),
+        "activity should be restored"
+    );
+    assert_eq!(
+        **app.world().get::<ContextPriority<Menu>>(menu).unwrap(),
+        5,
+        "priority should be restored"
+    );
+
+    app.world_mut().commands().pop_context();
+    app.world_mut().flush();
+
+    assert!(
+        **app
+            .world()
+            .get::<ContextActivity<Gameplay>>(gameplay)
+            .unwrap(),
+        "context that was active before the push should stay active"
+    );
+}
+
+#[test]
+fn blocking() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Gameplay>()
+        .add_input_context::<Menu>()
+        .finish();
+
+    let gameplay = app
+        .world_mut()
+        .spawn((
+            Gameplay,
+            actions!(Gameplay[(Action::<Test>::new(), bindings![Test::KEY])]),
+        ))
+        .id();
+    let menu = app
+        .world_mut()
+        .spawn((Menu, ContextBlocking::<Menu>::BOUND))
+        .id();
+
+    app.world_mut()
+        .commands()
+        .push_context::<Gameplay>(gameplay)
+        .push_blocking_context::<Menu>(menu);
+    app.world_mut().flush();
+
+    assert_eq!(
+        **app.world().get::<ContextBlocking<Menu>>(menu).unwrap(),
+        InputBlocking::All
+    );
+    assert!(
+        **app
+            .world()
+            .get::<ContextActivity<Gameplay>>(gameplay)
+            .unwrap(),
+        "blocked layers should stay active"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    assert_eq!(
+        action_state::<Gameplay>(&mut app, gameplay),
+        TriggerState::None,
+        "inputs should be blocked by the top layer"
+    );
+
+    app.world_mut().commands().pop_context();
+    app.world_mut().flush();
+
+    assert_eq!(
+        **app.world().get::<ContextBlocking<Menu>>(menu).unwrap(),
+        InputBlocking::Bound,
+        "blocking should be restored"
+    );
+
+    app.update();
+
+    assert_eq!(
+        action_state::<Gameplay>(&mut app, gameplay),
+        TriggerState::Fired
+    );
+}
+
+#[test]
+fn events() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Depths>()
+        .add_input_context::<Menu>()
+        .add_observer(|pushed: On<ContextPushed>, mut depths: ResMut<Depths>| {
+            assert!(pushed.layer.is::<Menu>());
+            depths.pushed.push(pushed.depth);
+        })
+        .add_observer(|popped: On<ContextPopped>, mut depths: ResMut<Depths>| {
+            depths.popped.push(popped.depth);
+        })
+        .finish();
+
+    let menu = app.world_mut().spawn(Menu).id();
+    let submenu = app.world_mut().spawn(Menu).id();
+
+    app.world_mut()
+        .commands()
+        .push_context::<Menu>(menu)
+        .push_context::<Menu>(submenu)
+        .pop_context()
+        .pop_context()
+        .pop_context();
+    app.world_mut().flush();
+
+    let depths = app.world().resource::<Depths>();
+    assert_eq!(depths.pushed, [0, 1]);
+    assert_eq!(
+        depths.popped,
+        [1, 0],
+        "popping an empty stack should be ignored"
+    );
+}
+
+fn action_state<C: Component>(app: &mut App, context: Entity) -> TriggerState {
+    let actions = app.world().get::<Actions<C>>(context).unwrap();
+    let action = *actions.iter().next().as_ref().unwrap();
+    *app.world().get::<TriggerState>(action).unwrap()
+}
+
+#[derive(Resource, Default)]
+struct Depths {
+    pushed: Vec<usize>,
+    popped: Vec<usize>,
+}
+
+#[derive(Component)]
+struct Gameplay;
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
@@ -0,0 +1,170 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::{Press, *};
+use test_log::test;
+
+#[test]
+fn events() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Triggered>()
+        .add_input_context::<Test>()
+        .add_observer(record)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            Test,
+            actions!(
+                Test[(
+                    DynamicAction::new("jump", ActionValueDim::Bool),
+                    Press::default(),
+                    bindings![KEY],
+                )]
+            ),
+        ))
+        .id();
+
+    app.update();
+
+    assert!(app.world().resource::<Triggered>().is_empty());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let triggered = app.world().resource::<Triggered>();
+    assert_eq!(
+        triggered.iter().map(|event| event.kind).collect::<Vec<_>>(),
+        [ActionEvents::START, ActionEvents::FIRE]
+    );
+    for event in &**triggered {
+        assert_eq!(event.context, context);
+        assert_eq!(event.name.as_str(), "jump");
+        assert_eq!(event.value, true.into());
+        assert_eq!(event.state, TriggerState::Fired);
+    }
+
+    app.world_mut().resource_mut::<Triggered>().clear();
+    app.update();
+
+    let triggered = app.world().resource::<Triggered>();
+    assert_eq!(
+        triggered.iter().map(|event| event.kind).collect::<Vec<_>>(),
+        [ActionEvents::COMPLETE]
+    );
+}
+
+#[test]
+fn value() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Test>()
+        .finish();
+
+    app.world_mut().spawn((
+        Test,
+        actions!(
+            Test[(
+                DynamicAction::new("move", ActionValueDim::Axis2D),
+                Bindings::spawn(Cardinal::wasd_keys()),
+            )]
+        ),
+    ));
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<(&Name, &ActionValue), With<DynamicAction>>();
+
+    let (name, &value) = actions.single(app.world()).unwrap();
+    assert_eq!(name.as_str(), "move");
+    assert_eq!(value, Vec2::ZERO.into());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let (_, &value) = actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::Y.into());
+}
+
+#[test]
+fn mock() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Triggered>()
+        .add_input_context::<Test>()
+        .add_observer(record)
+        .finish();
+
+    app.world_mut().spawn((
+        Test,
+        actions!(
+            Test[(
+                DynamicAction::new("zoom", ActionValueDim::Axis1D),
+                ActionMock::once(TriggerState::Fired, 0.5),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    let triggered = app.world().resource::<Triggered>();
+    let fire = triggered
+        .iter()
+        .find(|event| event.kind == ActionEvents::FIRE)
+        .expect("mocked action should fire");
+    assert_eq!(fire.name.as_str(), "zoom");
+    assert_eq!(fire.value, 0.5.into());
+}
+
+#[test]
+fn mock_dim_mismatch() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Triggered>()
+        .add_input_context::<Test>()
+        .add_observer(record)
+        .finish();
+
+    app.world_mut().spawn((
+        Test,
+        actions!(
+            Test[(
+                DynamicAction::new("zoom", ActionValueDim::Axis1D),
+                ActionMock::once(TriggerState::Fired, true),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    let triggered = app.world().resource::<Triggered>();
+    let fire = triggered
+        .iter()
+        .find(|event| event.kind == ActionEvents::FIRE)
+        .expect("mocked action should fire");
+    assert_eq!(fire.value, 1.0.into());
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&ActionValue, With<DynamicAction>>();
+    let value = *actions.single(app.world()).unwrap();
+    assert_eq!(value, 1.0.into());
+}
+
+fn record(event: On<DynamicActionEvent>, mut triggered: ResMut<Triggered>) {
+    triggered.push(event.clone());
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Triggered(Vec<DynamicActionEvent>);
+
+#[derive(Component)]
+struct Test;
+
+const KEY: KeyCode = KeyCode::KeyA;
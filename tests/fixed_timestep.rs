@@ -1,4 +1,8 @@
-use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy::{
+    input::{InputPlugin, mouse::MouseMotion},
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
 use bevy_enhanced_input::prelude::*;
 use test_log::test;
 
@@ -80,6 +84,83 @@ fn twice_in_one_frame() {
     );
 }
 
+#[test]
+fn buffered_twice_in_one_frame() {
+    let time_step = Time::<Fixed>::default().timestep() * 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .buffer_inputs_in::<FixedPreUpdate>()
+        .add_systems(FixedUpdate, sum_look)
+        .init_resource::<LookSum>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Look>::new(), bindings![Binding::mouse_motion()])]),
+    ));
+
+    app.update();
+
+    app.world_mut().write_message(MouseMotion {
+        delta: Vec2::new(4.0, 0.0),
+    });
+
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<LookSum>(),
+        Vec2::new(4.0, 0.0),
+        "delta should be split across runs instead of being counted twice"
+    );
+
+    let mut actions = app.world_mut().query::<&Action<Look>>();
+    let look = *actions.single(app.world()).unwrap();
+    assert_eq!(*look, Vec2::new(2.0, 0.0));
+}
+
+#[test]
+fn buffered_once_in_two_frames() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .buffer_inputs_in::<FixedPreUpdate>()
+        .add_systems(FixedUpdate, sum_look)
+        .init_resource::<LookSum>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Look>::new(), bindings![Binding::mouse_motion()])]),
+    ));
+
+    for delta in [1.0, 2.0, 3.0] {
+        app.world_mut().write_message(MouseMotion {
+            delta: Vec2::new(delta, 0.0),
+        });
+
+        app.update();
+    }
+
+    assert_eq!(
+        **app.world().resource::<LookSum>(),
+        Vec2::new(6.0, 0.0),
+        "deltas from frames without runs should be carried over"
+    );
+}
+
+fn sum_look(mut sum: ResMut<LookSum>, look: Single<&Action<Look>>) {
+    **sum += ***look;
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct LookSum(Vec2);
+
 #[derive(Component)]
 struct TestContext;
 
@@ -90,3 +171,7 @@ struct Test;
 impl Test {
     const KEY: KeyCode = KeyCode::KeyA;
 }
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Look;
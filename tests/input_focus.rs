@@ -0,0 +1,174 @@
+#![cfg(feature = "input_focus")]
+
+use bevy::{
+    input::InputPlugin,
+    input_focus::{
+        InputFocus, InputFocusVisible, directional_navigation::DirectionalNavigationMap,
+    },
+    math::CompassOctant,
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::{Press, *};
+use test_log::test;
+
+#[test]
+fn suppression() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        InputFocusPlugin,
+    ))
+    .add_input_context::<Player>()
+    .finish();
+
+    let player = app
+        .world_mut()
+        .spawn((
+            Player,
+            InputFocusPolicy::default(),
+            actions!(Player[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((
+            Player,
+            actions!(Player[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+    let text_field = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .insert_resource(InputFocus(Some(text_field)));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(action_state(&mut app, player), TriggerState::None);
+    assert_eq!(
+        action_state(&mut app, other),
+        TriggerState::Fired,
+        "contexts without the policy shouldn't be affected"
+    );
+
+    app.world_mut().resource_mut::<InputFocus>().clear();
+
+    app.update();
+
+    assert_eq!(action_state(&mut app, player), TriggerState::Fired);
+}
+
+#[test]
+fn require_visible() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        InputFocusPlugin,
+    ))
+    .add_input_context::<Player>()
+    .finish();
+
+    let player = app
+        .world_mut()
+        .spawn((
+            Player,
+            InputFocusPolicy::new(InputDevices::KEYBOARD).with_require_visible(true),
+            actions!(Player[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+    let button = app.world_mut().spawn_empty().id();
+    app.world_mut().insert_resource(InputFocus(Some(button)));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(action_state(&mut app, player), TriggerState::Fired);
+
+    app.world_mut().resource_mut::<InputFocusVisible>().0 = true;
+
+    app.update();
+
+    assert_eq!(action_state(&mut app, player), TriggerState::None);
+}
+
+#[test]
+fn navigation() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        InputFocusPlugin,
+    ))
+    .add_input_context::<Menu>()
+    .finish();
+
+    app.world_mut().spawn((
+        Menu,
+        actions!(
+            Menu[(
+                Action::<NavigateFocus>::new(),
+                Press::default(),
+                Bindings::spawn(Cardinal::arrows())
+            )]
+        ),
+    ));
+    let first = app.world_mut().spawn_empty().id();
+    let second = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .resource_mut::<DirectionalNavigationMap>()
+        .add_symmetrical_edge(first, second, CompassOctant::South);
+    app.world_mut().insert_resource(InputFocus(Some(first)));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowDown);
+
+    app.update();
+
+    assert_eq!(app.world().resource::<InputFocus>().0, Some(second));
+    assert!(app.world().resource::<InputFocusVisible>().0);
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<InputFocus>().0,
+        Some(second),
+        "should navigate only once per press"
+    );
+}
+
+fn action_state(app: &mut App, context: Entity) -> TriggerState {
+    let actions = app.world().get::<Actions<Player>>(context).unwrap();
+    let action = *actions.iter().next().as_ref().unwrap();
+    *app.world().get::<TriggerState>(action).unwrap()
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+impl Jump {
+    const KEY: KeyCode = KeyCode::Space;
+}
@@ -0,0 +1,278 @@
+use bevy::{input::InputPlugin, platform::collections::HashMap, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn value() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<TestSource>()
+        .add_input_source::<TestSource>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Volume>::new(), bindings![Volume::INPUT])]),
+    ));
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Volume>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.0);
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 0.5);
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.5);
+
+    app.world_mut().remove_resource::<TestSource>();
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.0, "missing source should be ignored");
+}
+
+#[test]
+fn consume() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<TestSource>()
+        .add_input_source::<TestSource>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[
+                (
+                    Action::<Consumer>::new(),
+                    ActionSettings {
+                        consume_input: true,
+                        ..Default::default()
+                    },
+                    bindings![Volume::INPUT],
+                ),
+                (Action::<Volume>::new(), bindings![Volume::INPUT]),
+            ]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 1.0);
+
+    app.update();
+
+    let mut consumers = app.world_mut().query::<&Action<Consumer>>();
+    let consumer = *consumers.single(app.world()).unwrap();
+    assert_eq!(*consumer, 1.0);
+
+    let mut actions = app.world_mut().query::<&Action<Volume>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.0, "input should be consumed");
+}
+
+#[test]
+fn require_reset() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<TestSource>()
+        .add_input_source::<TestSource>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 1.0);
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Volume>::new(),
+                ActionSettings {
+                    require_reset: true,
+                    ..Default::default()
+                },
+                bindings![Volume::INPUT]
+            )]
+        ),
+    ));
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Volume>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.0, "should wait for the input to reset");
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 0.0);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 1.0);
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 1.0);
+}
+
+#[test]
+fn rebind() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<TestSource>()
+        .add_input_source::<TestSource>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Volume>::new(), bindings![Volume::INPUT])]),
+    ));
+
+    const OTHER_ID: u32 = 8;
+    let mut source = app.world_mut().resource_mut::<TestSource>();
+    source.insert(Volume::INPUT_ID, 1.0);
+    source.insert(OTHER_ID, 0.5);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Volume>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 1.0);
+
+    let mut bindings = app.world_mut().query_filtered::<Entity, With<Binding>>();
+    let binding = bindings.single(app.world()).unwrap();
+    app.world_mut()
+        .entity_mut(binding)
+        .insert(Binding::custom::<TestSource>(OTHER_ID));
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.5, "should read the new input");
+}
+
+#[test]
+fn sources() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<TestSource>()
+        .add_input_source::<TestSource>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Volume>::new(), bindings![Volume::INPUT])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 1.0);
+    app.world_mut()
+        .resource_mut::<CustomSources>()
+        .set::<TestSource>(false);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Volume>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 0.0);
+
+    app.world_mut()
+        .resource_mut::<CustomSources>()
+        .set::<TestSource>(true);
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 1.0);
+}
+
+#[test]
+fn per_context_sources() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<TestSource>()
+        .add_input_source::<TestSource>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let mut disabled = CustomSources::default();
+    disabled.set::<TestSource>(false);
+    let disabled_context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            disabled,
+            actions!(TestContext[(Action::<Volume>::new(), bindings![Volume::INPUT])]),
+        ))
+        .id();
+    let enabled_context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Volume>::new(), bindings![Volume::INPUT])]),
+        ))
+        .id();
+
+    app.world_mut()
+        .resource_mut::<TestSource>()
+        .insert(Volume::INPUT_ID, 1.0);
+
+    app.update();
+
+    assert_eq!(volume(&app, disabled_context), 0.0);
+    assert_eq!(
+        volume(&app, enabled_context),
+        1.0,
+        "component should affect only its context"
+    );
+}
+
+fn volume(app: &App, context: Entity) -> f32 {
+    let actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    let action = *actions.iter().next().as_ref().unwrap();
+    **app.world().get::<Action<Volume>>(action).unwrap()
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct TestSource(HashMap<u32, f32>);
+
+impl InputSource for TestSource {
+    const NAME: &'static str = "Test";
+
+    fn value(&self, input: u32) -> ActionValue {
+        self.get(&input).copied().unwrap_or_default().into()
+    }
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Volume;
+
+impl Volume {
+    const INPUT_ID: u32 = 7;
+    const INPUT: Binding = Binding::custom::<TestSource>(Self::INPUT_ID);
+}
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Consumer;
@@ -0,0 +1,133 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .finish();
+
+    app.world_mut().spawn((
+        First,
+        actions!(First[(Action::<FirstAction>::new(), bindings![KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    app.add_input_context::<Second>();
+    app.world_mut().spawn((
+        Second,
+        actions!(Second[(Action::<SecondAction>::new(), bindings![KEY])]),
+    ));
+
+    app.update();
+
+    let mut first = app
+        .world_mut()
+        .query_filtered::<&ActionEvents, With<Action<FirstAction>>>();
+    let first_events = *first.single(app.world()).unwrap();
+    assert_eq!(
+        first_events,
+        ActionEvents::FIRE,
+        "rebuilding systems shouldn't affect existing contexts"
+    );
+
+    let mut second = app
+        .world_mut()
+        .query_filtered::<&ActionEvents, With<Action<SecondAction>>>();
+    let second_events = *second.single(app.world()).unwrap();
+    assert_eq!(second_events, ActionEvents::START | ActionEvents::FIRE);
+}
+
+#[test]
+fn schedule() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .finish();
+
+    app.update();
+
+    app.add_input_context_to::<Update, Second>();
+    app.world_mut().spawn((
+        Second,
+        actions!(Second[(Action::<SecondAction>::new(), bindings![KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<SecondAction>>>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+}
+
+#[test]
+fn condition() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .finish();
+
+    app.update();
+
+    app.add_input_condition::<Never>();
+    app.world_mut().spawn((
+        First,
+        actions!(First[(Action::<FirstAction>::new(), Never, bindings![KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<FirstAction>>>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+}
+
+#[derive(Component)]
+struct First;
+
+#[derive(Component)]
+struct Second;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct FirstAction;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct SecondAction;
+
+/// Condition that never triggers.
+#[derive(Component, Debug, Clone, Copy)]
+struct Never;
+
+impl InputCondition for Never {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        _value: ActionValue,
+    ) -> TriggerState {
+        TriggerState::None
+    }
+}
+
+const KEY: KeyCode = KeyCode::KeyA;
@@ -0,0 +1,243 @@
+use bevy::{
+    input::{
+        InputPlugin,
+        gamepad::{
+            GamepadConnection, GamepadConnectionEvent, RawGamepadButtonChangedEvent,
+            RawGamepadEvent,
+        },
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn join() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<PlayerSlots>()
+        .init_resource::<Joined>()
+        .add_observer(|joined: On<PlayerJoined>, mut slots: ResMut<Joined>| {
+            slots.push((joined.entity, joined.slot, joined.gamepad));
+        })
+        .finish();
+
+    let player = app
+        .world_mut()
+        .spawn(PlayerSlot(0))
+        .observe(|_: On<PlayerJoined>, mut commands: Commands| {
+            commands.insert_resource(PlayerObserved);
+        })
+        .id();
+    app.world_mut().spawn(PlayerSlot(1));
+    let gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), gamepad, "Pad");
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<PlayerSlots>().slot_of(gamepad),
+        None,
+        "should join only after pressing the button"
+    );
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::None
+    );
+
+    press(app.world_mut(), gamepad, GamepadButton::Start);
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<PlayerSlots>().slot_of(gamepad),
+        Some(0)
+    );
+    assert_eq!(
+        **app.world().resource::<Joined>(),
+        [(player, 0, gamepad)],
+        "should be triggered only on the entity with the matching slot"
+    );
+    assert!(app.world().contains_resource::<PlayerObserved>());
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Single(gamepad)
+    );
+}
+
+#[test]
+fn reconnect() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(PlayerSlots::default().with_join_button(None))
+        .finish();
+
+    let player = app.world_mut().spawn(PlayerSlot(0)).id();
+    let gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), gamepad, "Pad");
+
+    app.update();
+
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Single(gamepad)
+    );
+
+    app.world_mut().write_message(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Disconnected,
+    ));
+
+    app.update();
+
+    let slots = app.world().resource::<PlayerSlots>();
+    assert!(slots.is_occupied(0));
+    assert_eq!(slots.gamepad(0), None);
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::None
+    );
+
+    let other_gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), other_gamepad, "Other pad");
+    let new_gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), new_gamepad, "Pad");
+
+    app.update();
+
+    let slots = app.world().resource::<PlayerSlots>();
+    assert_eq!(slots.slot_of(new_gamepad), Some(0));
+    assert_eq!(
+        slots.slot_of(other_gamepad),
+        Some(1),
+        "different gamepad shouldn't take the disconnected slot"
+    );
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Single(new_gamepad)
+    );
+}
+
+#[test]
+fn reconnect_with_join_button() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<PlayerSlots>()
+        .finish();
+
+    let gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), gamepad, "Pad");
+    press(app.world_mut(), gamepad, GamepadButton::Start);
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<PlayerSlots>().slot_of(gamepad),
+        Some(0)
+    );
+
+    app.world_mut().write_message(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Disconnected,
+    ));
+
+    app.update();
+
+    let new_gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), new_gamepad, "Pad");
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<PlayerSlots>().gamepad(0),
+        None,
+        "gamepad of the same model should press the button to reclaim the slot"
+    );
+
+    press(app.world_mut(), new_gamepad, GamepadButton::Start);
+
+    app.update();
+
+    let slots = app.world().resource::<PlayerSlots>();
+    assert_eq!(slots.slot_of(new_gamepad), Some(0));
+    assert!(!slots.is_occupied(1));
+}
+
+#[test]
+fn leave() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(
+            PlayerSlots::default()
+                .with_join_button(None)
+                .with_leave_button(Some(GamepadButton::Select)),
+        )
+        .init_resource::<Left>()
+        .add_observer(|left: On<PlayerLeft>, mut slots: ResMut<Left>| {
+            slots.push((left.entity, left.slot, left.gamepad));
+        })
+        .finish();
+
+    let gamepad = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), gamepad, "Pad");
+
+    app.update();
+
+    assert!(app.world().resource::<PlayerSlots>().is_occupied(0));
+
+    press(app.world_mut(), gamepad, GamepadButton::Select);
+
+    app.update();
+
+    assert!(!app.world().resource::<PlayerSlots>().is_occupied(0));
+    assert_eq!(
+        **app.world().resource::<Left>(),
+        [(gamepad, 0, gamepad)],
+        "should be triggered on the gamepad without players"
+    );
+}
+
+#[test]
+fn max_players() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(PlayerSlots::new(1).with_join_button(None))
+        .finish();
+
+    let gamepad1 = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), gamepad1, "Pad");
+    let gamepad2 = app.world_mut().spawn_empty().id();
+    connect(app.world_mut(), gamepad2, "Pad");
+
+    app.update();
+
+    let slots = app.world().resource::<PlayerSlots>();
+    assert_eq!(slots.slot_of(gamepad1), Some(0));
+    assert_eq!(slots.slot_of(gamepad2), None);
+}
+
+fn connect(world: &mut World, gamepad: Entity, name: &str) {
+    world.write_message(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Connected {
+            name: name.into(),
+            vendor_id: Some(1),
+            product_id: Some(2),
+        },
+    ));
+}
+
+fn press(world: &mut World, gamepad: Entity, button: GamepadButton) {
+    world.write_message(RawGamepadEvent::Button(RawGamepadButtonChangedEvent::new(
+        gamepad, button, 1.0,
+    )));
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Joined(Vec<(Entity, usize, Entity)>);
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Left(Vec<(Entity, usize, Entity)>);
+
+#[derive(Resource)]
+struct PlayerObserved;
@@ -266,6 +266,54 @@ fn change() {
     assert_eq!(second_passthrough_state, TriggerState::Fired);
 }
 
+#[test]
+fn change_preserves_state() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            First,
+            actions!(
+                First[(
+                    Action::<FirstPassthrough>::new(),
+                    bindings![PASSTHROUGH_KEY]
+                )]
+            ),
+        ))
+        .id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(PASSTHROUGH_KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<(&ActionEvents, &ActionTime), With<Action<FirstPassthrough>>>();
+
+    let (&events, _) = actions.single(app.world()).unwrap();
+    assert_eq!(events, ActionEvents::START | ActionEvents::FIRE);
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(ContextPriority::<First>::new(1));
+
+    app.update();
+
+    let (&events, time) = actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::FIRE,
+        "priority change shouldn't reset the action"
+    );
+    assert!(time.fired_secs > 0.0);
+}
+
 #[derive(Component)]
 struct First;
 
@@ -0,0 +1,117 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn sync() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<InWater>()
+        .init_resource::<Inserts>()
+        .add_input_context::<Swimming>()
+        .sync_context_to_condition::<Swimming, _>(|in_water: Res<InWater>| **in_water)
+        .add_observer(
+            |_insert: On<Insert, ContextActivity<Swimming>>, mut inserts: ResMut<Inserts>| {
+                **inserts += 1;
+            },
+        )
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            Swimming,
+            actions!(Swimming[(Action::<Dive>::new(), bindings![Dive::KEY])]),
+        ))
+        .id();
+    let initial_inserts = **app.world().resource::<Inserts>();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Dive::KEY);
+
+    app.update();
+
+    assert!(
+        !**app
+            .world()
+            .get::<ContextActivity<Swimming>>(context)
+            .unwrap()
+    );
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.update();
+
+    assert_eq!(
+        **app.world().resource::<Inserts>(),
+        initial_inserts + 1,
+        "activity should be inserted only on change"
+    );
+
+    app.world_mut().resource_mut::<InWater>().0 = true;
+
+    app.update();
+
+    assert!(
+        **app
+            .world()
+            .get::<ContextActivity<Swimming>>(context)
+            .unwrap()
+    );
+    assert_eq!(state(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn run_condition() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Swimming>()
+        .sync_context_to_condition::<Swimming, _>(resource_exists::<InWater>)
+        .finish();
+
+    let context = app.world_mut().spawn(Swimming).id();
+
+    app.update();
+
+    assert!(
+        !**app
+            .world()
+            .get::<ContextActivity<Swimming>>(context)
+            .unwrap()
+    );
+
+    app.init_resource::<InWater>();
+
+    app.update();
+
+    assert!(
+        **app
+            .world()
+            .get::<ContextActivity<Swimming>>(context)
+            .unwrap()
+    );
+}
+
+fn state(app: &mut App) -> TriggerState {
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Dive>>>();
+    *actions.single(app.world()).unwrap()
+}
+
+#[derive(Resource, Default, Deref)]
+struct InWater(bool);
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Inserts(usize);
+
+#[derive(Component)]
+struct Swimming;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Dive;
+
+impl Dive {
+    const KEY: KeyCode = KeyCode::KeyQ;
+}
@@ -176,6 +176,46 @@ fn on_spawn() {
     );
 }
 
+#[test]
+fn activity_reason() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        StatesPlugin,
+        EnhancedInputPlugin,
+    ))
+    .init_state::<TestState>()
+    .init_resource::<Reasons>()
+    .add_input_context::<ContextB>()
+    .sync_context_to_state::<ContextB, TestState>()
+    .add_observer(
+        |activated: On<ContextActivated<ContextB>>, mut reasons: ResMut<Reasons>| {
+            reasons.push(activated.reason);
+        },
+    )
+    .finish();
+
+    app.world_mut().spawn((
+        ContextB,
+        ContextActivity::<ContextB>::INACTIVE,
+        ActiveInStates::<ContextB, _>::single(TestState::B),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<NextState<TestState>>()
+        .set(TestState::B);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<Reasons>(), [ActivityReason::State]);
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Reasons(Vec<ActivityReason>);
+
 #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
 enum TestState {
     #[default]
@@ -0,0 +1,213 @@
+use bevy::{
+    input::{
+        ButtonState, InputPlugin,
+        keyboard::{Key, KeyboardInput, NativeKey},
+    },
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn same_frame() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+
+    tap(app.world_mut(), &[Test::KEY]);
+
+    app.update();
+
+    assert!(
+        !app.world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(Test::KEY)
+    );
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::START | ActionEvents::FIRE,
+        "tap should be evaluated as pressed"
+    );
+
+    app.update();
+
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(events, ActionEvents::COMPLETE);
+}
+
+#[test]
+fn between_fixed_runs() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+
+    tap(app.world_mut(), &[Test::KEY]);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert!(events.is_empty(), "`FixedMain` shouldn't run yet");
+
+    app.update();
+
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::START | ActionEvents::FIRE,
+        "tap from the previous frame should be evaluated as pressed"
+    );
+
+    for _ in 0..2 {
+        app.update();
+    }
+
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(events, ActionEvents::COMPLETE);
+}
+
+#[test]
+fn modifier() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![KeyCode::ShiftLeft])]),
+    ));
+
+    app.update();
+
+    tap(app.world_mut(), &[KeyCode::ShiftLeft]);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::START | ActionEvents::FIRE,
+        "tapped modifier should be evaluated as pressed"
+    );
+}
+
+#[test]
+fn chord() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                bindings![Test::KEY.with_mod_keys(ModKeys::CONTROL)],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    tap(app.world_mut(), &[KeyCode::ControlLeft, Test::KEY]);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::START | ActionEvents::FIRE,
+        "tapped chord should be evaluated as pressed"
+    );
+}
+
+#[test]
+fn exact_mod_keys() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ActionSettings {
+                    exact_mod_keys: true,
+                    ..Default::default()
+                },
+                bindings![Test::KEY],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    tap(app.world_mut(), &[KeyCode::ShiftLeft, Test::KEY]);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert!(
+        events.is_empty(),
+        "tapped extra modifier should reject the binding"
+    );
+}
+
+/// Presses and releases the keys within a single frame.
+///
+/// Keys are pressed in order and released in reverse order.
+fn tap(world: &mut World, keys: &[KeyCode]) {
+    let presses = keys.iter().map(|&key| (key, ButtonState::Pressed));
+    let releases = keys.iter().rev().map(|&key| (key, ButtonState::Released));
+    for (key_code, state) in presses.chain(releases) {
+        world.write_message(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
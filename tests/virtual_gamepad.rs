@@ -0,0 +1,157 @@
+use bevy::{
+    input::{InputPlugin, touch::TouchPhase},
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn stick() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad = app
+        .world_mut()
+        .spawn(VirtualGamepad {
+            left_stick: Some(VirtualStick::new(STICK_CENTER, STICK_RADIUS)),
+            ..Default::default()
+        })
+        .id();
+
+    app.world_mut().spawn((
+        TestContext,
+        GamepadDevice::Single(gamepad),
+        actions!(
+            TestContext[(
+                Action::<Movement>::new(),
+                Bindings::spawn(Axial::left_stick())
+            )]
+        ),
+    ));
+
+    app.update();
+
+    touch(
+        &mut app,
+        0,
+        TouchPhase::Started,
+        STICK_CENTER + Vec2::splat(200.0),
+    );
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Movement>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        *action,
+        Vec2::ZERO,
+        "touch outside of the stick should be ignored"
+    );
+
+    touch(&mut app, 1, TouchPhase::Started, STICK_CENTER);
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, Vec2::ZERO);
+
+    touch(
+        &mut app,
+        1,
+        TouchPhase::Moved,
+        STICK_CENTER + Vec2::new(STICK_RADIUS / 2.0, 0.0),
+    );
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, Vec2::new(0.5, 0.0));
+
+    touch(
+        &mut app,
+        1,
+        TouchPhase::Moved,
+        STICK_CENTER + Vec2::new(0.0, STICK_RADIUS * 2.0),
+    );
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        *action,
+        Vec2::NEG_Y,
+        "should be clamped and the Y axis should point up"
+    );
+
+    touch(&mut app, 1, TouchPhase::Ended, STICK_CENTER);
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, Vec2::ZERO);
+}
+
+#[test]
+fn button() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn(VirtualGamepad {
+        buttons: vec![VirtualButton {
+            button: Jump::BUTTON,
+            area: BUTTON_AREA,
+        }],
+        ..Default::default()
+    });
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Jump>::new(), bindings![Jump::BUTTON])]),
+    ));
+
+    app.update();
+
+    touch(&mut app, 0, TouchPhase::Started, BUTTON_AREA.center());
+    app.update();
+
+    let mut actions = app.world_mut().query::<&TriggerState>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+
+    touch(&mut app, 0, TouchPhase::Ended, BUTTON_AREA.center());
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+}
+
+fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+    app.world_mut().write_message(TouchInput {
+        phase,
+        position,
+        window: Entity::PLACEHOLDER,
+        force: None,
+        id,
+    });
+}
+
+const STICK_CENTER: Vec2 = Vec2::splat(100.0);
+const STICK_RADIUS: f32 = 50.0;
+const BUTTON_AREA: Rect = Rect {
+    min: Vec2::splat(300.0),
+    max: Vec2::splat(400.0),
+};
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Movement;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+impl Jump {
+    const BUTTON: GamepadButton = GamepadButton::South;
+}
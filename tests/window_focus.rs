@@ -0,0 +1,365 @@
+#![cfg(feature = "window")]
+
+use bevy::{
+    input::InputPlugin,
+    prelude::*,
+    window::{PrimaryWindow, WindowFocused},
+};
+use bevy_enhanced_input::{context::ExternallyMocked, prelude::*};
+use test_log::test;
+
+#[test]
+fn reset() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(WindowFocusPolicy::Reset)
+        .add_input_context::<Test>()
+        .finish();
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut().spawn((
+        Test,
+        actions!(
+            Test[(
+                Action::<Flashlight>::new(),
+                Toggle::default(),
+                bindings![Flashlight::KEY]
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Flashlight::KEY);
+
+    app.update();
+
+    assert_eq!(state::<Flashlight>(&mut app), TriggerState::Fired);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Flashlight::KEY);
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: false,
+    });
+
+    app.update();
+
+    assert_eq!(
+        state::<Flashlight>(&mut app),
+        TriggerState::None,
+        "toggle should be reset"
+    );
+
+    app.update();
+
+    assert_eq!(state::<Flashlight>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn keep() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(WindowFocusPolicy::Reset)
+        .add_input_context::<Test>()
+        .finish();
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut().spawn((
+        Test,
+        WindowFocusPolicy::Keep,
+        actions!(
+            Test[(
+                Action::<Flashlight>::new(),
+                Toggle::default(),
+                bindings![Flashlight::KEY]
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Flashlight::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Flashlight::KEY);
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: false,
+    });
+
+    app.update();
+
+    assert_eq!(state::<Flashlight>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn keep_by_default() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Test>()
+        .finish();
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut().spawn((
+        Test,
+        actions!(
+            Test[(
+                Action::<Flashlight>::new(),
+                Toggle::default(),
+                bindings![Flashlight::KEY]
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Flashlight::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Flashlight::KEY);
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: false,
+    });
+
+    app.update();
+
+    assert_eq!(state::<Flashlight>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn other_window() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(WindowFocusPolicy::Reset)
+        .add_input_context::<Test>()
+        .finish();
+
+    let primary = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    let other = app.world_mut().spawn(Window::default()).id();
+    app.world_mut().spawn((
+        Test,
+        ContextWindow(other),
+        actions!(
+            Test[(
+                Action::<Flashlight>::new(),
+                Toggle::default(),
+                bindings![Flashlight::KEY]
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Flashlight::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Flashlight::KEY);
+    app.world_mut().write_message(WindowFocused {
+        window: primary,
+        focused: false,
+    });
+
+    app.update();
+
+    assert_eq!(
+        state::<Flashlight>(&mut app),
+        TriggerState::Fired,
+        "focus of other windows should be ignored"
+    );
+}
+
+#[test]
+fn require_release() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(WindowFocusPolicy::ResetAndRequireRelease)
+        .add_input_context::<Test>()
+        .finish();
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut().spawn((
+        Test,
+        actions!(Test[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+    ));
+
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: false,
+    });
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: true,
+    });
+
+    app.update();
+
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::None,
+        "inputs held on focus gain should be ignored"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Jump::KEY);
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(state::<Jump>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn require_release_per_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(WindowFocusPolicy::ResetAndRequireRelease)
+        .add_input_context::<Test>()
+        .finish();
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    let reset = app
+        .world_mut()
+        .spawn((
+            Test,
+            actions!(Test[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+    let kept = app
+        .world_mut()
+        .spawn((
+            Test,
+            WindowFocusPolicy::Keep,
+            actions!(Test[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: false,
+    });
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: true,
+    });
+
+    app.update();
+    app.update();
+
+    assert_eq!(context_state(&app, reset), TriggerState::None);
+    assert_eq!(
+        context_state(&app, kept),
+        TriggerState::Fired,
+        "inputs should be ignored only for contexts with the policy"
+    );
+}
+
+#[test]
+fn externally_mocked() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(WindowFocusPolicy::Reset)
+        .add_input_context::<Test>()
+        .finish();
+
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.world_mut().spawn((
+        Test,
+        actions!(Test[(Action::<Jump>::new(), ExternallyMocked, TriggerState::Fired)]),
+    ));
+
+    app.world_mut().write_message(WindowFocused {
+        window,
+        focused: false,
+    });
+
+    app.update();
+
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::Fired,
+        "externally mocked actions shouldn't be reset"
+    );
+}
+
+fn context_state(app: &App, context: Entity) -> TriggerState {
+    let actions = app.world().get::<Actions<Test>>(context).unwrap();
+    let action = *actions.iter().next().as_ref().unwrap();
+    *app.world().get::<TriggerState>(action).unwrap()
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>();
+    *actions.single(app.world()).unwrap()
+}
+
+#[derive(Component)]
+struct Test;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Flashlight;
+
+impl Flashlight {
+    const KEY: KeyCode = KeyCode::KeyF;
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+impl Jump {
+    const KEY: KeyCode = KeyCode::Space;
+}